@@ -5,7 +5,7 @@
 //!
 //! ```
 //! use rkyv::Archive;
-//! use rkyv_impl::*;
+//! use rkyv_impl::{archive_impl, archive_method};
 //! use std::iter::Sum;
 //!
 //! #[derive(Archive)]
@@ -36,19 +36,126 @@
 //!     let _ = foo.sum::<S>();
 //! }
 //! ```
+//!
+//! ## `rkyv` versions
+//!
+//! Generated bounds and projections only name the `Archive` trait (which must
+//! be in scope) and its `Archived` associated type. These are the same in
+//! `rkyv` 0.7 and 0.8. The code generated by `gen_try_from` and
+//! `gen_roundtrip_test` calls `rkyv` 0.7 APIs, so those options require 0.7.
 
+use indexmap::IndexMap;
 use proc_macro::TokenStream;
-use proc_macro2::{Ident, Span};
-use quote::quote;
-use std::collections::HashSet;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned};
 use syn::{
-    parse::Parser, parse_macro_input, parse_quote, punctuated::Punctuated, visit_mut::VisitMut,
-    GenericParam, Generics, ImplItem, ImplItemFn, ItemImpl, Meta, Token, Type, TypePath,
+    parse::{Parse, ParseStream, Parser},
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    visit::Visit,
+    visit_mut::{self, VisitMut},
+    Attribute, Block, Expr, ExprLit, FnArg, GenericArgument, GenericParam, Generics, ImplItem,
+    ImplItemFn, Item, ItemImpl, ItemMod, Lit, LitStr, Meta, MetaNameValue, Path, PathArguments,
+    PathSegment, QSelf, ReturnType, Signature, Token, Type, TypeParamBound, TypePath, Visibility,
     WhereClause, WherePredicate,
 };
 
 /// Supports the same arguments as [`macro@archive_impl`], but applies to
 /// methods on an `impl` block.
+///
+/// Also supports some arguments that only make sense for a single method.
+///
+/// # `skip`
+///
+/// Omits the method from the generated `impl`.
+///
+/// # `keep`
+///
+/// Copies the method to the generated `impl` even if it would be left out
+/// otherwise, like a constructor (see
+/// [Associated functions](macro@archive_impl#associated-functions)) or a
+/// `&mut self` method under
+/// [`skip_mut_methods`](macro@archive_impl#skip_mut_methods) or a by-value
+/// `self` method under
+/// [`skip_by_value_self`](macro@archive_impl#skip_by_value_self).
+///
+/// # `transform_return`
+///
+/// For each given parameter `T`, adds a `T: Archive` bound and transforms `T`
+/// into `T::Archived` in the return type of the generated method. References
+/// and other types wrapping `T` are preserved, so `Option<&T>` becomes
+/// `Option<&T::Archived>`.
+///
+/// # `archived_containers`
+///
+/// `rkyv` archives `Vec<T>` as `ArchivedVec<T::Archived>`, not as
+/// `Vec<T::Archived>`. This flag maps `Vec`, `String`, and `Box` to
+/// `rkyv::vec::ArchivedVec`, `rkyv::string::ArchivedString`, and
+/// `rkyv::boxed::ArchivedBox` in the return type, and in the argument types
+/// with `transform_all`. Combined with `transform_return(T)`, a method returning
+/// `&Vec<T>` returns `&ArchivedVec<T::Archived>` on the archived type.
+///
+/// # `cfg_archived`
+///
+/// Only compiles the generated method when the given feature is enabled, like
+/// `cfg_archived = "archived_extras"`. The original method is not gated.
+///
+/// # `transform_bounds_from_impl`
+///
+/// Applies the `transform_bounds` parameters of the enclosing `archive_impl` to
+/// the method's `where` clause, without listing them again. Parameters also
+/// given to the method's own `transform_bounds` keep the method's projection.
+/// See also [`transform_methods`](macro@archive_impl#transform_methods).
+///
+/// # `vis`
+///
+/// Overrides the visibility of the generated method, like `vis = "pub(crate)"`.
+/// An empty string makes it private. The original method is unchanged.
+///
+/// # `transform_all`
+///
+/// Combines `transform_bounds` and `transform_return`, and also transforms `T`
+/// in the argument types, so `T` becomes `T::Archived` everywhere in the
+/// signature of the generated method. The `self` receiver is left alone.
+///
+/// # `add_generic`
+///
+/// Adds generic params to the generated method only, like
+/// `add_generic(D: Fallible)`. Their bounds are used as written. This is
+/// mostly useful with `archived_body`, for example to deserialize with a
+/// deserializer chosen by the caller.
+///
+/// # `archived_body`
+///
+/// Replaces the body of the generated method with the given block, for when
+/// the archived type needs an entirely different implementation. The original
+/// method keeps its own body.
+///
+/// This includes error handling. If the archived body calls different
+/// functions, the `?` operator may need to convert different error types into
+/// the method's error type.
+///
+/// For enums, a `match` that names variants as `Foo::Variant` needs an
+/// archived body naming `ArchivedFoo::Variant`. Writing `Self::Variant`
+/// instead works on both types, as long as the arms compile for the archived
+/// fields.
+///
+/// ```
+/// # use rkyv::Archive;
+/// # use rkyv_impl::*;
+/// #[derive(Archive)]
+/// struct Foo {
+///     name: String,
+/// }
+///
+/// #[archive_impl]
+/// impl Foo {
+///     #[archive_method(archived_body = { self.name.as_str().to_owned() })]
+///     fn owned_name(&self) -> String {
+///         self.name.clone()
+///     }
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn archive_method(_: TokenStream, item: TokenStream) -> TokenStream {
     // No-op that just fails if placed on anything but a method. Arguments are
@@ -56,26 +163,275 @@ pub fn archive_method(_: TokenStream, item: TokenStream) -> TokenStream {
     let cloned_item = item.clone();
     let parsed = parse_macro_input!(cloned_item as ImplItem);
     match parsed {
-        ImplItem::Fn(_) => (),
-        unsupported_item => {
-            let item_verbatim = quote! { #unsupported_item };
-            panic!(
-                "Unsupported item `{item_verbatim}`. `archive_method` can only be applied to methods."
-            );
-        }
+        ImplItem::Fn(_) => item,
+        unsupported_item => syn::Error::from(ArchiveImplError::UnsupportedItem(Box::new(
+            unsupported_item,
+        )))
+        .to_compile_error()
+        .into(),
     }
-    item
 }
 
 /// Decorates an `impl T` (or `impl FooTrait for T`) block and generates an
 /// equivalent `impl T::Archived`.
 ///
 /// The original `impl` block is not modified, but the generated block can be
-/// modified according to the macro arguments.
+/// modified according to the macro arguments. Everything else is copied,
+/// including `unsafe impl` and the `!` of a negative impl.
+///
+/// The self type must `#[derive(Archive)]`, and the archived type must be
+/// nameable by prefixing the last path segment with `Archived`, e.g. `Foo` and
+/// `ArchivedFoo`. Otherwise, the generated `impl` fails to compile with an
+/// error pointing at the self type. The rest of the path is kept, so if `Foo`
+/// is used through a re-export like `crate::Foo`, then `ArchivedFoo` must be
+/// re-exported there too. Blanket impls like `impl<T> Trait for T`
+/// are rejected, since there is no archived type to name.
+///
+/// Copied items keep their original spans. If a method body doesn't compile
+/// for the archived type (like calling `push` on an `ArchivedVec`), the error
+/// points at the original line. See [`skip_mut_methods`](#skip_mut_methods)
+/// and [`macro@archive_method`] for leaving such methods out.
+///
+/// Flag arguments like `skip_mut_methods` can also be written as
+/// `skip_mut_methods = true` or `skip_mut_methods = false`.
 ///
 /// Note that generated bounds are only added to the `where` clause on the
 /// `impl`. To transform or add bounds to specific methods, see
-/// [`macro@archive_method`].
+/// [`macro@archive_method`]. The generated `impl` must still satisfy any bounds
+/// on `ArchivedFoo` itself, like those from rkyv's
+/// `#[archive(bound(archive = "..."))]`, which can be repeated with
+/// [`add_bounds`](#add_bounds). The `serialize` and `deserialize` bounds only
+/// apply to rkyv's own impls.
+///
+/// # Debugging
+///
+/// Set `RKYV_IMPL_DEBUG=1` while building to print the expansion of each
+/// `#[archive_impl]` to stderr, including the original `impl`. Cargo only shows
+/// the output of a crate that is rebuilt, so `touch` the source file or run
+/// `cargo clean -p` first.
+///
+/// # Associated functions
+///
+/// Associated functions without a `self` receiver that return an owned `Self`,
+/// `Option<Self>` or `Result<Self, E>` (like `fn new() -> Self`) are assumed to
+/// be constructors. An archived type can't be constructed like the original,
+/// so these are left out of the generated `impl` with a warning. Use
+/// `#[archive_method(keep)]` to copy one anyway, or `#[archive_method(skip)]`
+/// to leave it out without a warning. Functions returning references to
+/// `Self` are copied as usual, and so is every function in a trait `impl`,
+/// like `Default::default`.
+///
+/// # Macro invocations
+///
+/// Macro invocations in the `impl` (like `my_methods!();`) are copied verbatim
+/// and expanded separately for each type. Any methods they generate are not
+/// transformed, and `archive_method` can't be applied to them.
+///
+/// # Modules
+///
+/// `#[archive_impl]` can also be applied to an inline `mod`, which is the same
+/// as applying it with the same arguments to each `impl` directly inside the
+/// module. Other items, including nested modules, are left unchanged. The
+/// items from `gen_try_from`, `gen_roundtrip_test`, `gen_ord` and `gen_hash`
+/// are only generated once for each type name, with its first `impl`, so
+/// `impl<T> Foo<T>` and `impl Foo<u32>` share them.
+///
+/// # Conditional compilation
+///
+/// A `#[cfg(...)]` on the `impl` applies to both generated blocks, whether it
+/// comes before or after `#[archive_impl]`. If `#[derive(Archive)]` is gated
+/// (e.g. `#[cfg_attr(feature = "rkyv", derive(Archive))]`), then the archived
+/// type only exists under that condition, and the `impl` must be gated the
+/// same way, or use [`cfg_archived`](#cfg_archived) to only gate the generated
+/// items.
+///
+/// Attributes like `#[cfg_attr(test, inline)]` on items in the `impl` are
+/// copied as written. However, `cfg_attr` is not evaluated before
+/// `archive_impl` runs, so an `archive_method` inside one (like
+/// `#[cfg_attr(feature = "x", archive_method(skip))]`) is ignored for the
+/// generated `impl`. Use [`cfg_archived`](macro@archive_method#cfg_archived)
+/// to gate an archived method instead.
+///
+/// # Other attribute macros
+///
+/// Attribute macros on the same `impl` expand from top to bottom. One written
+/// above `#[archive_impl]` runs first and only sees the original `impl`. One
+/// written below stays on the original `impl` and is copied to the archived
+/// `impl`, so it is applied to both blocks separately.
+///
+/// Attributes on methods, like `#[cold]` or `#[inline]`, are copied to the
+/// archived methods too. This includes `#[no_mangle]` and `#[export_name]`,
+/// which then export the same symbol twice and fail to compile. There's no way
+/// to rename the archived symbol, so use `#[archive_method(skip)]` on these
+/// methods and write the archived version by hand if needed.
+///
+/// # `cfg_archived`
+///
+/// Only compiles the generated `impl` (and any other generated items for the
+/// archived type) when the given feature is enabled, like
+/// `cfg_archived = "rkyv"`. The original `impl` is always emitted. This is
+/// meant for an optional `rkyv` dependency, where the derive is gated like
+/// `#[cfg_attr(feature = "rkyv", derive(Archive))]`.
+///
+/// # `archived_trait`
+///
+/// For a trait `impl`, implements the given trait for the archived type
+/// instead of the original trait, like `archived_trait = "GetSliceArchived"`.
+/// The methods are copied as usual, so their signatures must also match the
+/// new trait.
+///
+/// # `archived_methods_only`
+///
+/// Only copies methods that have an `#[archive_method]` attribute (with or
+/// without arguments) to the generated `impl`. Other methods only exist on the
+/// original type. Associated types and constants are still copied.
+///
+/// # `archived_first`
+///
+/// Emits the generated `impl` before the original one. This only matters when
+/// another macro depends on the order of items, like an attribute that only
+/// sees the item directly following it.
+///
+/// # `track_caller`
+///
+/// Adds `#[track_caller]` to every generated method, so a panic while
+/// accessing archived data (like an out-of-bounds index) reports the caller's
+/// location. A `#[track_caller]` already on a method is copied either way.
+///
+/// # `allow_lints`
+///
+/// Adds `#[allow(...)]` with the given lints to the generated `impl` only, like
+/// `allow_lints(non_snake_case, clippy::len_without_is_empty)`. This helps when
+/// a renamed archived type or the copied methods trip lints that don't apply to
+/// the original `impl`.
+///
+/// # `transform_self_args`
+///
+/// Also renames the given types in the generic arguments of the self type, so
+/// `impl Wrapper<Foo>` under `transform_self_args(Foo)` generates
+/// `impl ArchivedWrapper<ArchivedFoo>`. By default, only the self type itself
+/// is renamed.
+///
+/// # `keep_self_wrapper`
+///
+/// Keeps the outer self type as written and only renames the types given to
+/// [`transform_self_args`](#transform_self_args), so
+/// `impl Trait for Cow<'_, Foo>` under
+/// `keep_self_wrapper, transform_self_args(Foo)` generates
+/// `impl Trait for Cow<'_, ArchivedFoo>`. This also works for self types that
+/// aren't paths, like `&Foo` or `[Foo]`. Without `transform_self_args` the
+/// generated `impl` would be the same as the original, so that's an error.
+///
+/// # `archived_self`
+///
+/// Replaces the generated self type entirely, like
+/// `archived_self = "ArchivedFoo"`, for when the archived type doesn't follow
+/// the naming of the original (e.g. a custom `Archive` impl that drops a
+/// generic parameter). Parameters of the `impl` that no longer appear in the
+/// self type or trait are removed from the generated `impl`, along with any
+/// bounds that mention them. Methods that use them fail to compile.
+///
+/// # `skip_mut_methods`
+///
+/// Leaves methods with a `&mut self` receiver out of the generated `impl`.
+/// Archived data is usually only accessed through a shared reference, so
+/// these methods often can't compile for the archived type. This includes
+/// fluent methods like `fn push(&mut self, x: u32) -> &mut Self`, while
+/// methods returning `&Self` are copied and return `&ArchivedFoo`.
+///
+/// # `no_mut`
+///
+/// Copies methods with a `&mut self` receiver with a `&self` receiver instead.
+/// Only the receiver changes, so a body that needs `&mut` access fails to
+/// compile for the archived type; skip those methods with `skip_mut_methods`
+/// or `#[archive_method(skip)]`.
+///
+/// # `skip_by_value_self`
+///
+/// Leaves methods that take `self` by value (like `fn into_parts(self)`) out
+/// of the generated `impl`. Archived data lives behind a reference into the
+/// buffer, so it can't be moved out of.
+///
+/// # `gen_try_from`
+///
+/// Also generates `impl TryFrom<&ArchivedFoo> for Foo`, which deserializes with
+/// `rkyv::Deserialize`. The `Error` is the deserializer's error. By default,
+/// the deserializer is `rkyv::Infallible`, but any deserializer that implements
+/// `Default` can be used, like
+/// `gen_try_from(deserializer = "rkyv::de::deserializers::SharedDeserializeMap")`.
+///
+/// The generated `impl` has the same generics and bounds as the archived
+/// `impl`, plus an `ArchivedFoo: Deserialize<Foo, D>` bound. Only use this on
+/// one `impl` block per type, and only with `rkyv` as a direct dependency.
+///
+/// # `gen_roundtrip_test`
+///
+/// Also generates a `#[cfg(test)]` test that serializes a sample value,
+/// accesses the archived value, and asserts that a method returns equal results
+/// on both, like
+/// `gen_roundtrip_test(sample = "sample_foo", method = "get_slice")`. The
+/// sample is a function in scope returning a `Foo`, and the method takes no
+/// arguments. This requires `rkyv::Serialize`, and `rkyv` as a direct
+/// dependency.
+///
+/// # `gen_ord` and `gen_hash`
+///
+/// The fields of `ArchivedFoo` aren't visible to the macro, so these delegate
+/// to a method in the `impl` instead, named like `gen_ord = "key"`. The method
+/// must have the signature `fn key(&self) -> K`, where `K` implements `Ord`
+/// (for `gen_ord`) or `Hash` (for `gen_hash`) on the archived type.
+///
+/// `gen_ord` generates `PartialEq`, `Eq`, `PartialOrd` and `Ord` for
+/// `ArchivedFoo`, comparing the keys. `gen_hash` generates `Hash`, hashing the
+/// key, which must agree with the `Eq` impl, e.g. by using the same key for
+/// both. The generated impls have the same generics and bounds as the archived
+/// `impl`.
+///
+/// # `doc_link`
+///
+/// Appends ``See also [`Foo::method`].``, linking to the original method, to
+/// the docs of each generated method.
+///
+/// # `gen_size_helper`
+///
+/// Adds `pub const fn archived_size() -> usize` to the generated `impl`, which
+/// returns `size_of::<ArchivedFoo>()`, e.g. for sizing buffers up front. For a
+/// generic type, it's only available under the bounds of the `impl`. Only
+/// supported on an inherent `impl`.
+///
+/// # `Self`
+///
+/// `Self` is never rewritten. In the generated `impl` it already refers to the
+/// archived type, so a bound like `T: AsRef<Self>` means
+/// `T::Archived: AsRef<ArchivedFoo<T>>` under `transform_bounds(T)`.
+///
+/// The same goes for associated consts, which are copied as-is. A const like
+/// `const N: usize = Self::compute();` needs `ArchivedFoo::compute` to exist,
+/// e.g. by defining `compute` in the same `impl` block.
+///
+/// A method bound like `where Self: Clone` becomes `ArchivedFoo: Clone`. If
+/// `ArchivedFoo` doesn't implement the trait and has no generics, the bound is
+/// trivially false, which is a compile error on stable Rust, so use
+/// `#[archive_method(skip)]` on such methods.
+///
+/// Method bodies are copied as-is too, so `Self` in a body (including in a
+/// turbofish like `Vec::<Self>::new()`) always means the self type of the
+/// respective `impl`.
+///
+/// # `rewrite_self_calls`
+///
+/// Paths that name the self type explicitly, like `Foo::helper()`, still refer
+/// to `Foo` in the generated `impl`. With `rewrite_self_calls`, paths through
+/// the self type in copied method bodies are renamed, so `Foo::helper()`
+/// becomes `ArchivedFoo::helper()`. This applies to every path in the body,
+/// including patterns like `Foo::Variant(x) =>`, struct expressions like
+/// `Foo::Variant { .. }`, and types like `let x: Foo::Assoc`.
+///
+/// Only paths starting with a bare `Foo`, or with the same prefix as the self
+/// type (like `crate::Foo::helper()` for `impl crate::Foo`), are renamed, so a
+/// different `other::Foo` is left alone. So are qualified paths like
+/// `<Foo as Trait>::helper()`, paths in macro invocations, and a bare `Foo`
+/// (use `Self` instead).
 ///
 /// # `transform_bounds`
 ///
@@ -83,6 +439,32 @@ pub fn archive_method(_: TokenStream, item: TokenStream) -> TokenStream {
 /// into `T::Archived` in all pre-existing trait bounds on the `impl`. Can take
 /// a list of multiple parameters, like `transform_bounds(T, S)`.
 ///
+/// Parameters that are only used in `PhantomData` don't need to implement
+/// `Archive`, and stay as they are in the archived type. Leave them out of
+/// `transform_bounds`; methods can still transform them with
+/// [`macro@archive_method`].
+///
+/// By default, `T` becomes `T::Archived`. A different projection can be given
+/// per parameter, like `transform_bounds(T: path("Inner::Archived"))`, which
+/// transforms `T` into `<T::Inner as Archive>::Archived` and adds a
+/// `T::Inner: Archive` bound instead.
+///
+/// To only change the name of the associated type, `assoc` maps transformed
+/// parameters to names, like `assoc(T = "Archived", U = "Frozen")`, which
+/// transforms `U` into `U::Frozen`. The `U: Archive` bound is still added
+/// unless [`no_archive_bounds`](#no_archive_bounds) is given.
+///
+/// `T` is also transformed in the definitions of associated types, like
+/// `type Item = T`. For generic associated types, only the definition is
+/// transformed; the generics and `where` clause must match the trait.
+///
+/// Bounds of `T` on the `Archive` trait itself, like `T: Archive<Archived = U>`,
+/// are not transformed, since they already describe `T::Archived`.
+///
+/// Associated type and const bindings are kept as written, so
+/// `T: Dims<DIMS = { 3 }>` becomes `T::Archived: Dims<DIMS = { 3 }>`. Only
+/// types inside them are transformed.
+///
 /// ## Example
 ///
 /// Given the following usage of `transform_bounds`:
@@ -124,6 +506,52 @@ pub fn archive_method(_: TokenStream, item: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # `transform_methods`
+///
+/// Applies the `transform_bounds` of the `impl` to the `where` clauses of the
+/// named methods too, like `transform_methods(sum, clone_element)`. This is
+/// shorthand for adding `#[archive_method(transform_bounds(...))]` with the
+/// same parameters to each of them. Other methods are copied as usual.
+///
+/// # `no_archive_bounds`
+///
+/// Stops `transform_bounds` (and the other transforms) from adding `T: Archive`
+/// bounds, on the `impl` and all of its methods. Existing bounds are still
+/// transformed, so the needed bounds must be given some other way, like with
+/// `add_bounds`.
+///
+/// # `bound_trait`
+///
+/// Uses another trait in place of `Archive` for the bounds added by the
+/// transforms, on the `impl` and all of its methods, like
+/// `bound_trait = "my::MyArchive"`. The associated type is still `Archived`
+/// unless renamed with `assoc`. For the archived methods to compile, the trait
+/// must tie that type to `Archive::Archived`, e.g.
+/// `trait MyArchive: Archive<Archived = Self::Frozen>`.
+///
+/// # `map_type`
+///
+/// Replaces a type in the return types of the generated methods (and the
+/// argument types with `transform_all`), for containers that archive to a type
+/// of their own, like `map_type(MyWrapper<T> => MyArchivedWrapper<T::Archived>)`.
+/// Only types written exactly like the left side are replaced, and the right
+/// side is used as written. This can also be given to `archive_method` for a
+/// single method. See also
+/// [`archived_containers`](macro@archive_method#archived_containers) for the
+/// std containers.
+///
+/// # `preserve_bounds`
+///
+/// Exempts specific bounds from `transform_bounds`, like
+/// `preserve_bounds(T: Send)`, so they stay on `T` itself. Other bounds on `T`
+/// are still transformed, even when declared in the same predicate. Each entry
+/// must match an existing bound.
+///
+/// Otherwise, `transform_bounds` is all-or-nothing per parameter. A bound like
+/// `(T, V): Trait` can't have only `T` transformed on the `impl`, but it can be
+/// moved to a method with `#[archive_method(transform_bounds(T))]`, since
+/// method bounds are only transformed by the method's own arguments.
+///
 /// # `add_bounds`
 ///
 /// Adds bounds to the generated `impl`. Takes a list of predicates, for
@@ -168,56 +596,743 @@ pub fn archive_method(_: TokenStream, item: TokenStream) -> TokenStream {
 /// ```
 #[proc_macro_attribute]
 pub fn archive_impl(args: TokenStream, item: TokenStream) -> TokenStream {
-    let impl_args = match Arguments::parse(args) {
+    let impl_args = match Arguments::parse(args, ArgumentScope::Impl) {
         Ok(a) => a,
         Err(e) => {
             return e.to_compile_error().into();
         }
     };
 
-    let orig_impl = parse_macro_input!(item as ItemImpl);
+    let expanded = if let Ok(module) = syn::parse::<ItemMod>(item.clone()) {
+        expand_module(impl_args, module)
+    } else {
+        let orig_impl = parse_macro_input!(item as ItemImpl);
+        expand_impl(impl_args, orig_impl)
+    };
+    expanded
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+// Replaces each `impl` directly inside the module with its expansion. A module
+// without a body (`mod foo;`) is rejected by the compiler before this, or has
+// its contents loaded inline.
+//
+// The items generated for the archived type (`gen_try_from`, `gen_ord`, etc.)
+// would conflict if emitted for each `impl` of the same type, so they're only
+// emitted for the first one.
+fn expand_module(impl_args: Arguments, mut module: ItemMod) -> syn::Result<TokenStream2> {
+    let mut seen_self_types = Vec::new();
+    let items = module.content.iter_mut().flat_map(|(_, items)| items);
+    for item in items {
+        if let Item::Impl(orig_impl) = item {
+            // Keyed on the type name, so `Foo<T>` and `Foo<u32>`, or `Foo` and
+            // `crate::Foo`, count as the same archived type.
+            let self_ty = &orig_impl.self_ty;
+            let self_ty = match self_type_ident(self_ty) {
+                Some(ident) => ident.to_string(),
+                None => quote!(#self_ty).to_string(),
+            };
+            let mut args = impl_args.clone();
+            if seen_self_types.contains(&self_ty) {
+                args.gen_try_from = None;
+                args.gen_roundtrip_test = None;
+                args.gen_ord = None;
+                args.gen_hash = None;
+            } else {
+                seen_self_types.push(self_ty);
+            }
+            let expanded = expand_impl(args, orig_impl.clone())?;
+            *item = Item::Verbatim(expanded);
+        }
+    }
+    Ok(quote! { #module })
+}
+
+fn expand_impl(mut impl_args: Arguments, orig_impl: ItemImpl) -> syn::Result<TokenStream2> {
+    let gen_try_from = impl_args.gen_try_from.take();
+    let gen_roundtrip_test = impl_args.gen_roundtrip_test.take();
+    let gen_ord = impl_args.gen_ord.take();
+    let gen_hash = impl_args.gen_hash.take();
+    let archived_first = impl_args.archived_first;
+    // Trait impls must define every required item, so constructors like
+    // `Default::default` are always copied.
+    let constructor_warnings = if impl_args.archived_methods_only || orig_impl.trait_.is_some() {
+        None
+    } else {
+        Some(skipped_constructor_warnings(&orig_impl))
+    };
+    let archived_impl = generate_archived_impl(impl_args, &orig_impl)?;
+    let try_from_impl =
+        gen_try_from.map(|options| generate_try_from_impl(options, &orig_impl, &archived_impl));
+    let roundtrip_test = gen_roundtrip_test
+        .map(|options| generate_roundtrip_test(options, &orig_impl, &archived_impl));
+    let ord_impls = gen_ord.map(|key| generate_ord_impls(&key, &archived_impl));
+    let hash_impl = gen_hash.map(|key| generate_hash_impl(&key, &archived_impl));
+
+    let impls = if archived_first {
+        quote! { #archived_impl #orig_impl }
+    } else {
+        quote! { #orig_impl #archived_impl }
+    };
+    let expanded = quote! {
+        #impls
+        #constructor_warnings
+        #try_from_impl
+        #roundtrip_test
+        #ord_impls
+        #hash_impl
+    };
+    if std::env::var("RKYV_IMPL_DEBUG").as_deref() == Ok("1") {
+        eprintln!("{expanded}");
+    }
+    Ok(expanded)
+}
+
+// The last segment of the self type, like `Foo` for `crate::Foo<T>`, or
+// `None` if the self type isn't a path.
+fn self_type_ident(self_ty: &Type) -> Option<Ident> {
+    let mut self_ty = self_ty.clone();
+    unwrap_self_type(&mut self_ty);
+    match self_ty {
+        Type::Path(path) => path.path.segments.last().map(|last| last.ident.clone()),
+        _ => None,
+    }
+}
+
+// The `#[cfg]` attributes of the archived `impl`, which also gate the other
+// items generated for the archived type.
+fn cfg_attrs(archived_impl: &ItemImpl) -> Vec<&Attribute> {
+    archived_impl
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .collect()
+}
+
+// Generates `PartialEq`, `Eq`, `PartialOrd` and `Ord` for the archived type,
+// comparing the results of the `key` method.
+fn generate_ord_impls(key: &Ident, archived_impl: &ItemImpl) -> TokenStream2 {
+    let archived_self_ty = &archived_impl.self_ty;
+    let (impl_generics, _, where_clause) = archived_impl.generics.split_for_impl();
+    let cfgs = &cfg_attrs(archived_impl);
+    quote! {
+        #(#cfgs)*
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::PartialEq for #archived_self_ty #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                self.#key() == other.#key()
+            }
+        }
+
+        #(#cfgs)*
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::Eq for #archived_self_ty #where_clause {}
+
+        #(#cfgs)*
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::PartialOrd for #archived_self_ty #where_clause {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        #(#cfgs)*
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::Ord for #archived_self_ty #where_clause {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(&self.#key(), &other.#key())
+            }
+        }
+    }
+}
+
+// Generates `Hash` for the archived type, hashing the result of the `key`
+// method.
+fn generate_hash_impl(key: &Ident, archived_impl: &ItemImpl) -> ItemImpl {
+    let archived_self_ty = &archived_impl.self_ty;
+    let (impl_generics, _, where_clause) = archived_impl.generics.split_for_impl();
+    let cfgs = cfg_attrs(archived_impl);
+    parse_quote! {
+        #(#cfgs)*
+        #[automatically_derived]
+        impl #impl_generics ::core::hash::Hash for #archived_self_ty #where_clause {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(&self.#key(), state);
+            }
+        }
+    }
+}
+
+// Generates `impl TryFrom<&ArchivedFoo> for Foo`, under the same generics as
+// the archived impl.
+fn generate_try_from_impl(
+    options: GenTryFrom,
+    orig_impl: &ItemImpl,
+    archived_impl: &ItemImpl,
+) -> ItemImpl {
+    let self_ty = &orig_impl.self_ty;
+    let archived_self_ty = &archived_impl.self_ty;
+    let (deserializer_ty, deserializer): (Type, Expr) = match options.deserializer {
+        Some(ty) => (
+            ty.clone(),
+            parse_quote! { <#ty as ::core::default::Default>::default() },
+        ),
+        None => (
+            parse_quote! { ::rkyv::Infallible },
+            parse_quote! { ::rkyv::Infallible },
+        ),
+    };
+
+    let mut generics = archived_impl.generics.clone();
+    generics.params.insert(0, parse_quote! { 'archived });
+    generics.make_where_clause().predicates.push(parse_quote! {
+        #archived_self_ty: ::rkyv::Deserialize<#self_ty, #deserializer_ty>
+    });
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let cfgs = cfg_attrs(archived_impl);
+
+    // With the default deserializer, the conversion can't fail, which trips
+    // up some lints.
+    parse_quote! {
+        #(#cfgs)*
+        #[automatically_derived]
+        #[allow(clippy::all)]
+        impl #impl_generics ::core::convert::TryFrom<&'archived #archived_self_ty> for #self_ty
+        #where_clause
+        {
+            type Error = <#deserializer_ty as ::rkyv::Fallible>::Error;
+
+            fn try_from(
+                archived: &'archived #archived_self_ty,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                ::rkyv::Deserialize::deserialize(archived, &mut #deserializer)
+            }
+        }
+    }
+}
+
+// Generates a `#[cfg(test)]` module that archives the sample value and compares
+// the method's result on both types.
+fn generate_roundtrip_test(
+    options: GenRoundtripTest,
+    orig_impl: &ItemImpl,
+    archived_impl: &ItemImpl,
+) -> TokenStream2 {
+    let GenRoundtripTest { sample, method } = options;
+    let type_name = self_type_ident(&orig_impl.self_ty)
+        .map(|ident| ident.to_string())
+        .unwrap_or_default();
+    let mod_name = format_ident!("__rkyv_impl_roundtrip_{type_name}_{method}");
+    let cfgs = cfg_attrs(archived_impl);
+
+    quote! {
+        #(#cfgs)*
+        #[cfg(test)]
+        #[allow(non_snake_case, clippy::all)]
+        mod #mod_name {
+            use super::*;
+
+            // Lets the archived type be inferred from the sample.
+            fn archived_root<'a, T: ::rkyv::Archive>(_: &T, bytes: &'a [u8]) -> &'a T::Archived {
+                unsafe { ::rkyv::archived_root::<T>(bytes) }
+            }
+
+            #[test]
+            fn roundtrip() {
+                let value = #sample();
+                let bytes = ::rkyv::to_bytes::<_, 256>(&value).expect("failed to serialize sample");
+                let archived = archived_root(&value, &bytes);
+                assert_eq!(value.#method(), archived.#method());
+            }
+        }
+    }
+}
+
+fn generate_archived_impl(mut impl_args: Arguments, orig_impl: &ItemImpl) -> syn::Result<ItemImpl> {
+    validate_transform_params(&impl_args.transform_params, &[&orig_impl.generics])?;
 
     // Clone the original impl to be sure we don't accidentally drop anything,
     // we only need to find and transform certain parts.
     let mut archived_impl = orig_impl.clone();
-    replace_self_type(&mut archived_impl.self_ty);
-    transform_generics(&impl_args.transform_params, &mut archived_impl.generics);
+    if let Some(feature) = impl_args.cfg_archived.take() {
+        archived_impl
+            .attrs
+            .push(parse_quote! { #[cfg(feature = #feature)] });
+    }
+    unwrap_self_type(&mut archived_impl.self_ty);
+    reject_blanket_self_type(&archived_impl.self_ty, &orig_impl.generics)?;
+    let archived_self = impl_args.archived_self.take();
+    let prune_generics = archived_self.is_some();
+    if let Some(archived_self) = archived_self {
+        *archived_impl.self_ty = archived_self;
+    } else if impl_args.keep_self_wrapper {
+        // Only the named types are renamed, wherever they appear.
+        SelfArgRenamer {
+            names: &impl_args.transform_self_args,
+        }
+        .visit_type_mut(&mut archived_impl.self_ty);
+    } else {
+        replace_self_type(&mut archived_impl.self_ty)?;
+        rename_self_args(&impl_args.transform_self_args, &mut archived_impl.self_ty);
+    }
+    if let Some(archived_trait) = impl_args.archived_trait.take() {
+        replace_trait(archived_trait, &mut archived_impl)?;
+    }
+    transform_generics(
+        &impl_args.transform_params,
+        &impl_args.preserve_bounds,
+        &mut archived_impl.generics,
+    )?;
     add_bounds_to_where_clause(
-        impl_args.add_bounds,
+        std::mem::take(&mut impl_args.add_bounds),
         &mut archived_impl.generics.where_clause,
     );
-    if let Err(e) = augment_methods(&mut archived_impl.items) {
-        return e.to_compile_error().into();
+    if prune_generics {
+        prune_unused_generics(&mut archived_impl);
     }
-
-    quote! {
-        #orig_impl
-        #archived_impl
+    transform_assoc_types(&impl_args.transform_params, &mut archived_impl.items);
+    augment_methods(&mut archived_impl.items, &impl_args, orig_impl)?;
+    if impl_args.rewrite_self_calls {
+        rewrite_self_calls(&orig_impl.self_ty, &mut archived_impl.items);
     }
-    .into()
+    if impl_args.doc_link {
+        add_doc_links(&orig_impl.self_ty, &mut archived_impl.items);
+    }
+    if impl_args.gen_size_helper {
+        add_size_helper(&mut archived_impl)?;
+    }
+    let allow_lints = &impl_args.allow_lints;
+    if !allow_lints.is_empty() {
+        archived_impl
+            .attrs
+            .push(parse_quote! { #[allow(#(#allow_lints),*)] });
+    }
+    Ok(archived_impl)
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct Arguments {
     add_bounds: Vec<WherePredicate>,
-    transform_params: Vec<Ident>,
+    add_generics: Vec<GenericParam>,
+    preserve_bounds: Vec<WherePredicate>,
+    transform_params: Vec<TransformParam>,
+    transform_return_params: Vec<TransformParam>,
+    transform_input_params: Vec<TransformParam>,
+    type_mappings: Vec<TypeMapping>,
+    transform_self_args: Vec<Ident>,
+    transform_methods: Vec<Ident>,
+    no_archive_bounds: bool,
+    skip: bool,
+    keep: bool,
+    archived_body: Option<Block>,
+    cfg_archived: Option<LitStr>,
+    vis: Option<Visibility>,
+    archived_trait: Option<Path>,
+    skip_mut_methods: bool,
+    no_mut: bool,
+    skip_by_value_self: bool,
+    gen_try_from: Option<GenTryFrom>,
+    gen_roundtrip_test: Option<GenRoundtripTest>,
+    gen_ord: Option<Ident>,
+    gen_hash: Option<Ident>,
+    archived_methods_only: bool,
+    archived_first: bool,
+    track_caller: bool,
+    gen_size_helper: bool,
+    doc_link: bool,
+    allow_lints: Vec<Path>,
+    keep_self_wrapper: bool,
+    archived_self: Option<Type>,
+    bound_trait: Option<Path>,
+    rewrite_self_calls: bool,
 }
 
 impl Arguments {
-    fn parse(args: TokenStream) -> syn::Result<Self> {
-        let mut builder = ArgumentsBuilder::default();
+    fn parse(args: TokenStream, scope: ArgumentScope) -> syn::Result<Self> {
+        let mut builder = ArgumentsBuilder::new(scope);
         builder.try_add_metas_token_stream(args)?;
-        Ok(builder.build())
+        builder.build()
+    }
+}
+
+// The associated path inserted after a transformed param, e.g. `Archived`.
+type Projection = Punctuated<PathSegment, Token![::]>;
+
+// A param given to `transform_bounds(T)` or `transform_bounds(T: path("..."))`.
+#[derive(Clone)]
+struct TransformParam {
+    ident: Ident,
+    projection: Projection,
+    // The trait that declares the last segment of the projection.
+    bound_trait: Path,
+}
+
+// A type rewritten in the transformed parts of a signature.
+#[derive(Clone)]
+enum TypeMapping {
+    // Renames a type path, keeping its generic arguments, e.g. `Vec<T>` to
+    // `ArchivedVec<T>`.
+    Path { from: Path, to: Path },
+    // Replaces a type written exactly like `from`, from `map_type`.
+    Type { from: Box<Type>, to: Box<Type> },
+}
+
+impl TypeMapping {
+    fn matches_path(&self, path: &Path) -> bool {
+        let TypeMapping::Path { from, .. } = self else {
+            return false;
+        };
+        path.segments.len() == from.segments.len()
+            && path
+                .segments
+                .iter()
+                .zip(&from.segments)
+                .all(|(a, b)| a.ident == b.ident)
+    }
+
+    // `syn` types only implement `PartialEq` with the `extra-traits` feature,
+    // so they're compared by their tokens.
+    fn matches_type(&self, ty: &Type) -> bool {
+        let TypeMapping::Type { from, .. } = self else {
+            return false;
+        };
+        quote!(#from).to_string() == quote!(#ty).to_string()
+    }
+}
+
+impl Parse for TypeMapping {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let from = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let to = input.parse()?;
+        Ok(TypeMapping::Type { from, to })
+    }
+}
+
+// The `rkyv` archived types of the std containers, for `archived_containers`.
+// Each container matches by its name or its full path in `std` or `alloc`.
+fn archived_containers() -> Vec<TypeMapping> {
+    let containers: [(Ident, Ident, Path); 3] = [
+        (
+            parse_quote!(vec),
+            parse_quote!(Vec),
+            parse_quote!(::rkyv::vec::ArchivedVec),
+        ),
+        (
+            parse_quote!(string),
+            parse_quote!(String),
+            parse_quote!(::rkyv::string::ArchivedString),
+        ),
+        (
+            parse_quote!(boxed),
+            parse_quote!(Box),
+            parse_quote!(::rkyv::boxed::ArchivedBox),
+        ),
+    ];
+    let mut mappings = Vec::new();
+    for (module, name, to) in containers {
+        let paths: [Path; 3] = [
+            parse_quote!(#name),
+            parse_quote!(std::#module::#name),
+            parse_quote!(alloc::#module::#name),
+        ];
+        mappings.extend(paths.into_iter().map(|from| TypeMapping::Path {
+            from,
+            to: to.clone(),
+        }));
+    }
+    mappings
+}
+
+impl Parse for TransformParam {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse()?;
+        let projection = if input.parse::<Option<Token![:]>>()?.is_some() {
+            parse_projection(&input.parse()?)?
+        } else {
+            parse_quote! { Archived }
+        };
+        Ok(Self {
+            ident,
+            projection,
+            bound_trait: parse_quote! { Archive },
+        })
+    }
+}
+
+fn into_transform_params(
+    params: IndexMap<Ident, Projection>,
+    bound_trait: &Path,
+) -> Vec<TransformParam> {
+    params
+        .into_iter()
+        .map(|(ident, projection)| TransformParam {
+            ident,
+            projection,
+            bound_trait: bound_trait.clone(),
+        })
+        .collect()
+}
+
+// Options for `gen_try_from`.
+#[derive(Clone, Default)]
+struct GenTryFrom {
+    // Defaults to `rkyv::Infallible`.
+    deserializer: Option<Type>,
+}
+
+// Options for `gen_roundtrip_test`.
+#[derive(Clone)]
+struct GenRoundtripTest {
+    sample: Path,
+    method: Ident,
+}
+
+/// Which attribute the arguments were given to. Some arguments only make sense
+/// on a single method.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ArgumentScope {
+    Impl,
+    Method,
+}
+
+impl ArgumentScope {
+    fn macro_name(self) -> &'static str {
+        match self {
+            Self::Impl => "archive_impl",
+            Self::Method => "archive_method",
+        }
+    }
+}
+
+/// Everything that can be wrong with the input to the macros. Each error is
+/// reported as a [`syn::Error`] at the span of the offending tokens.
+enum ArchiveImplError {
+    /// `archive_method` on something other than a method.
+    UnsupportedItem(Box<ImplItem>),
+    /// The self type isn't a path, like `impl &Foo`.
+    UnsupportedSelfType(Type),
+    /// The self type is a bare type parameter, like `impl<T> Trait for T`.
+    BlanketImpl(Type),
+    UnknownArgument(Path),
+    /// The argument isn't supported by the macro it was given to.
+    WrongScope(Meta, ArgumentScope),
+    /// The argument has the wrong form, e.g. `skip = "..."` instead of `skip`.
+    UnsupportedMeta {
+        meta: Meta,
+        expected: &'static str,
+    },
+    UnknownOption {
+        option: Path,
+        argument: &'static str,
+    },
+    MissingOptions {
+        meta: Meta,
+        argument: &'static str,
+        options: &'static str,
+    },
+    /// The argument has no effect without another one.
+    MissingArgument {
+        meta: Meta,
+        argument: &'static str,
+        required: &'static str,
+    },
+    UndeclaredTransformParam(Ident),
+    /// `assoc` names a parameter that isn't transformed.
+    UntransformedAssocParam(Ident),
+    /// An argument that only applies to trait impls was used on an inherent
+    /// impl, or vice versa.
+    WrongImplKind {
+        tokens: TokenStream2,
+        argument: &'static str,
+        expected: &'static str,
+    },
+    UnsupportedPreserveBound(WherePredicate),
+    UnmatchedPreserveBound(WherePredicate),
+    UnknownTransformMethod(Ident),
+}
+
+impl From<ArchiveImplError> for syn::Error {
+    fn from(error: ArchiveImplError) -> Self {
+        match error {
+            ArchiveImplError::UnsupportedItem(item) => {
+                let item_verbatim = quote! { #item };
+                syn::Error::new_spanned(
+                    item,
+                    format!(
+                        "unsupported item `{item_verbatim}`: `archive_method` can only be applied to methods"
+                    ),
+                )
+            }
+            ArchiveImplError::UnsupportedSelfType(self_ty) => {
+                let self_ty_verbatim = quote! { #self_ty };
+                syn::Error::new_spanned(
+                    self_ty,
+                    format!("`impl {self_ty_verbatim}` unsupported: self type can only be a path"),
+                )
+            }
+            ArchiveImplError::BlanketImpl(self_ty) => {
+                let self_ty_verbatim = quote! { #self_ty };
+                syn::Error::new_spanned(
+                    self_ty,
+                    format!(
+                        "blanket `impl` over the type parameter `{self_ty_verbatim}` is not supported"
+                    ),
+                )
+            }
+            ArchiveImplError::UnknownArgument(path) => {
+                let path_verbatim = quote! { #path };
+                syn::Error::new_spanned(path, format!("unsupported argument `{path_verbatim}`"))
+            }
+            ArchiveImplError::WrongScope(meta, scope) => {
+                let meta_path = meta.path();
+                let meta_path = quote! { #meta_path };
+                let macro_name = scope.macro_name();
+                syn::Error::new_spanned(
+                    meta,
+                    format!("`{meta_path}` is only supported by `{macro_name}`"),
+                )
+            }
+            ArchiveImplError::UnsupportedMeta { meta, expected } => {
+                let meta_verbatim = quote! { #meta };
+                syn::Error::new_spanned(
+                    meta,
+                    format!("unsupported `{meta_verbatim}`: meta can only be {expected}"),
+                )
+            }
+            ArchiveImplError::UnknownOption { option, argument } => {
+                syn::Error::new_spanned(option, format!("unsupported `{argument}` option"))
+            }
+            ArchiveImplError::MissingOptions {
+                meta,
+                argument,
+                options,
+            } => syn::Error::new_spanned(meta, format!("`{argument}` requires {options}")),
+            ArchiveImplError::MissingArgument {
+                meta,
+                argument,
+                required,
+            } => syn::Error::new_spanned(
+                meta,
+                format!("`{argument}` has no effect without `{required}`"),
+            ),
+            ArchiveImplError::UndeclaredTransformParam(param) => syn::Error::new_spanned(
+                &param,
+                format!("`{param}` is not a type parameter in scope"),
+            ),
+            ArchiveImplError::UntransformedAssocParam(param) => syn::Error::new_spanned(
+                &param,
+                format!("`assoc` names `{param}`, but `{param}` is not transformed"),
+            ),
+            ArchiveImplError::WrongImplKind {
+                tokens,
+                argument,
+                expected,
+            } => syn::Error::new_spanned(
+                tokens,
+                format!("`{argument}` can only be used on {expected}"),
+            ),
+            ArchiveImplError::UnsupportedPreserveBound(predicate) => syn::Error::new_spanned(
+                predicate,
+                "`preserve_bounds` only supports type bounds like `T: Send`",
+            ),
+            ArchiveImplError::UnmatchedPreserveBound(predicate) => syn::Error::new_spanned(
+                predicate,
+                "`preserve_bounds` entry doesn't match any bound",
+            ),
+            ArchiveImplError::UnknownTransformMethod(name) => syn::Error::new_spanned(
+                &name,
+                format!(
+                    "`transform_methods` names `{name}`, but there is no such method in this `impl`"
+                ),
+            ),
+        }
     }
 }
 
-#[derive(Default)]
 struct ArgumentsBuilder {
+    scope: ArgumentScope,
     add_bounds: Vec<WherePredicate>,
-    transform_params: HashSet<Ident>,
+    add_generics: Vec<GenericParam>,
+    preserve_bounds: Vec<WherePredicate>,
+    transform_params: IndexMap<Ident, Projection>,
+    transform_return_params: IndexMap<Ident, Projection>,
+    transform_input_params: IndexMap<Ident, Projection>,
+    type_mappings: Vec<TypeMapping>,
+    assoc: Vec<(Ident, Ident)>,
+    transform_self_args: Vec<Ident>,
+    transform_methods: Vec<Ident>,
+    no_archive_bounds: bool,
+    skip: bool,
+    keep: bool,
+    archived_body: Option<Block>,
+    cfg_archived: Option<LitStr>,
+    vis: Option<Visibility>,
+    archived_trait: Option<Path>,
+    skip_mut_methods: bool,
+    no_mut: bool,
+    skip_by_value_self: bool,
+    gen_try_from: Option<GenTryFrom>,
+    gen_roundtrip_test: Option<GenRoundtripTest>,
+    gen_ord: Option<Ident>,
+    gen_hash: Option<Ident>,
+    archived_methods_only: bool,
+    archived_first: bool,
+    track_caller: bool,
+    gen_size_helper: bool,
+    doc_link: bool,
+    allow_lints: Vec<Path>,
+    // Kept for the span of the error if `transform_self_args` is missing.
+    keep_self_wrapper: Option<Meta>,
+    archived_self: Option<Type>,
+    bound_trait: Option<Path>,
+    rewrite_self_calls: bool,
+    transform_bounds_from_impl: bool,
 }
 
 impl ArgumentsBuilder {
+    fn new(scope: ArgumentScope) -> Self {
+        Self {
+            scope,
+            add_bounds: Vec::new(),
+            add_generics: Vec::new(),
+            preserve_bounds: Vec::new(),
+            transform_params: IndexMap::new(),
+            transform_return_params: IndexMap::new(),
+            transform_input_params: IndexMap::new(),
+            type_mappings: Vec::new(),
+            assoc: Vec::new(),
+            transform_self_args: Vec::new(),
+            transform_methods: Vec::new(),
+            no_archive_bounds: false,
+            skip: false,
+            keep: false,
+            archived_body: None,
+            cfg_archived: None,
+            vis: None,
+            archived_trait: None,
+            skip_mut_methods: false,
+            no_mut: false,
+            skip_by_value_self: false,
+            gen_try_from: None,
+            gen_roundtrip_test: None,
+            gen_ord: None,
+            gen_hash: None,
+            archived_methods_only: false,
+            archived_first: false,
+            track_caller: false,
+            gen_size_helper: false,
+            doc_link: false,
+            allow_lints: Vec::new(),
+            keep_self_wrapper: None,
+            archived_self: None,
+            bound_trait: None,
+            rewrite_self_calls: false,
+            transform_bounds_from_impl: false,
+        }
+    }
+
     fn try_add_metas_token_stream(&mut self, args: TokenStream) -> syn::Result<()> {
         if !args.is_empty() {
             let mut arg_metas = Vec::new();
@@ -232,131 +1347,982 @@ impl ArgumentsBuilder {
     fn try_add_meta(&mut self, meta: &Meta) -> syn::Result<()> {
         if meta.path().is_ident("transform_bounds") {
             parse_transform_bounds(meta, &mut self.transform_params)?;
+        } else if meta.path().is_ident("transform_return") {
+            self.require_scope(meta, ArgumentScope::Method)?;
+            parse_transform_bounds(meta, &mut self.transform_return_params)?;
+        } else if meta.path().is_ident("transform_all") {
+            self.require_scope(meta, ArgumentScope::Method)?;
+            let mut params = IndexMap::new();
+            parse_transform_bounds(meta, &mut params)?;
+            self.transform_params.extend(params.clone());
+            self.transform_return_params.extend(params.clone());
+            self.transform_input_params.extend(params);
+        } else if meta.path().is_ident("archived_containers") {
+            self.require_scope(meta, ArgumentScope::Method)?;
+            if parse_flag(meta)? {
+                self.type_mappings.extend(archived_containers());
+            }
+        } else if meta.path().is_ident("map_type") {
+            parse_map_type(meta, &mut self.type_mappings)?;
+        } else if meta.path().is_ident("assoc") {
+            parse_assoc(meta, &mut self.assoc)?;
         } else if meta.path().is_ident("add_bounds") {
             parse_add_bounds(meta, &mut self.add_bounds)?;
+        } else if meta.path().is_ident("add_generic") {
+            self.require_scope(meta, ArgumentScope::Method)?;
+            parse_add_generic(meta, &mut self.add_generics)?;
+        } else if meta.path().is_ident("no_archive_bounds") {
+            self.no_archive_bounds = parse_flag(meta)?;
+        } else if meta.path().is_ident("preserve_bounds") {
+            parse_add_bounds(meta, &mut self.preserve_bounds)?;
+        } else if meta.path().is_ident("skip") {
+            self.require_scope(meta, ArgumentScope::Method)?;
+            self.skip = parse_flag(meta)?;
+        } else if meta.path().is_ident("keep") {
+            self.require_scope(meta, ArgumentScope::Method)?;
+            self.keep = parse_flag(meta)?;
+        } else if meta.path().is_ident("archived_body") {
+            self.require_scope(meta, ArgumentScope::Method)?;
+            self.archived_body = Some(parse_archived_body(meta)?);
+        } else if meta.path().is_ident("cfg_archived") {
+            self.cfg_archived = Some(parse_lit_str(meta)?);
+        } else if meta.path().is_ident("vis") {
+            self.require_scope(meta, ArgumentScope::Method)?;
+            self.vis = Some(parse_str_value(meta)?);
+        } else if meta.path().is_ident("archived_trait") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.archived_trait = Some(parse_str_value(meta)?);
+        } else if meta.path().is_ident("skip_mut_methods") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.skip_mut_methods = parse_flag(meta)?;
+        } else if meta.path().is_ident("no_mut") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.no_mut = parse_flag(meta)?;
+        } else if meta.path().is_ident("skip_by_value_self") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.skip_by_value_self = parse_flag(meta)?;
+        } else if meta.path().is_ident("transform_self_args") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            parse_ident_list(meta, &mut self.transform_self_args)?;
+        } else if meta.path().is_ident("transform_methods") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            parse_ident_list(meta, &mut self.transform_methods)?;
+        } else if meta.path().is_ident("gen_try_from") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.gen_try_from = Some(parse_gen_try_from(meta)?);
+        } else if meta.path().is_ident("gen_roundtrip_test") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.gen_roundtrip_test = Some(parse_gen_roundtrip_test(meta)?);
+        } else if meta.path().is_ident("gen_ord") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.gen_ord = Some(parse_str_value(meta)?);
+        } else if meta.path().is_ident("gen_hash") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.gen_hash = Some(parse_str_value(meta)?);
+        } else if meta.path().is_ident("archived_methods_only") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.archived_methods_only = parse_flag(meta)?;
+        } else if meta.path().is_ident("archived_first") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.archived_first = parse_flag(meta)?;
+        } else if meta.path().is_ident("track_caller") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.track_caller = parse_flag(meta)?;
+        } else if meta.path().is_ident("gen_size_helper") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.gen_size_helper = parse_flag(meta)?;
+        } else if meta.path().is_ident("doc_link") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.doc_link = parse_flag(meta)?;
+        } else if meta.path().is_ident("allow_lints") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            parse_path_list(meta, &mut self.allow_lints)?;
+        } else if meta.path().is_ident("keep_self_wrapper") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.keep_self_wrapper = parse_flag(meta)?.then(|| meta.clone());
+        } else if meta.path().is_ident("archived_self") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.archived_self = Some(parse_str_value(meta)?);
+        } else if meta.path().is_ident("bound_trait") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.bound_trait = Some(parse_str_value(meta)?);
+        } else if meta.path().is_ident("rewrite_self_calls") {
+            self.require_scope(meta, ArgumentScope::Impl)?;
+            self.rewrite_self_calls = parse_flag(meta)?;
+        } else if meta.path().is_ident("transform_bounds_from_impl") {
+            self.require_scope(meta, ArgumentScope::Method)?;
+            self.transform_bounds_from_impl = parse_flag(meta)?;
         } else {
-            let meta_path = meta.path().get_ident().unwrap();
-            panic!("Unsupported argument `{meta_path}`");
+            return Err(ArchiveImplError::UnknownArgument(meta.path().clone()).into());
         }
         Ok(())
     }
 
-    fn build(mut self) -> Arguments {
-        for param in &self.transform_params {
-            self.add_bounds.push(parse_quote! { #param: Archive });
+    fn require_scope(&self, meta: &Meta, scope: ArgumentScope) -> syn::Result<()> {
+        if self.scope != scope {
+            return Err(ArchiveImplError::WrongScope(meta.clone(), scope).into());
         }
-        Arguments {
-            add_bounds: self.add_bounds,
-            transform_params: self.transform_params.into_iter().collect(),
+        Ok(())
+    }
+
+    fn build(mut self) -> syn::Result<Arguments> {
+        if let Some(meta) = &self.keep_self_wrapper {
+            if self.transform_self_args.is_empty() {
+                return Err(ArchiveImplError::MissingArgument {
+                    meta: meta.clone(),
+                    argument: "keep_self_wrapper",
+                    required: "transform_self_args",
+                }
+                .into());
+            }
+        }
+        for (param, assoc) in &self.assoc {
+            let mut found = false;
+            for params in [
+                &mut self.transform_params,
+                &mut self.transform_return_params,
+                &mut self.transform_input_params,
+            ] {
+                if let Some(projection) = params.get_mut(param) {
+                    *projection = Projection::from_iter([PathSegment::from(assoc.clone())]);
+                    found = true;
+                }
+            }
+            if !found {
+                return Err(ArchiveImplError::UntransformedAssocParam(param.clone()).into());
+            }
+        }
+        let mut archive_bounds = self.transform_params.clone();
+        archive_bounds.extend(self.transform_return_params.clone());
+        archive_bounds.extend(self.transform_input_params.clone());
+        if self.no_archive_bounds {
+            archive_bounds.clear();
         }
+        let bound_trait = self
+            .bound_trait
+            .clone()
+            .unwrap_or_else(|| parse_quote! { Archive });
+        for (param, projection) in archive_bounds {
+            // The last segment of the projection is the `Archive` associated
+            // type, so the bound goes on everything before it.
+            let prefix = projection.iter().take(projection.len() - 1);
+            self.add_bounds
+                .push(parse_quote! { #param #(:: #prefix)*: #bound_trait });
+        }
+        Ok(Arguments {
+            add_bounds: self.add_bounds,
+            add_generics: self.add_generics,
+            preserve_bounds: self.preserve_bounds,
+            transform_params: into_transform_params(self.transform_params, &bound_trait),
+            transform_return_params: into_transform_params(
+                self.transform_return_params,
+                &bound_trait,
+            ),
+            transform_input_params: into_transform_params(
+                self.transform_input_params,
+                &bound_trait,
+            ),
+            type_mappings: self.type_mappings,
+            transform_self_args: self.transform_self_args,
+            transform_methods: self.transform_methods,
+            no_archive_bounds: self.no_archive_bounds,
+            skip: self.skip,
+            keep: self.keep,
+            archived_body: self.archived_body,
+            cfg_archived: self.cfg_archived,
+            vis: self.vis,
+            archived_trait: self.archived_trait,
+            skip_mut_methods: self.skip_mut_methods,
+            no_mut: self.no_mut,
+            skip_by_value_self: self.skip_by_value_self,
+            gen_try_from: self.gen_try_from,
+            gen_roundtrip_test: self.gen_roundtrip_test,
+            gen_ord: self.gen_ord,
+            gen_hash: self.gen_hash,
+            archived_methods_only: self.archived_methods_only,
+            archived_first: self.archived_first,
+            track_caller: self.track_caller,
+            gen_size_helper: self.gen_size_helper,
+            doc_link: self.doc_link,
+            allow_lints: self.allow_lints,
+            keep_self_wrapper: self.keep_self_wrapper.is_some(),
+            archived_self: self.archived_self,
+            bound_trait: self.bound_trait,
+            rewrite_self_calls: self.rewrite_self_calls,
+        })
+    }
+}
+
+// A self type passed through a `macro_rules!` `$t:ty` fragment arrives as an
+// invisible group, and `impl (Foo)` is parenthesized.
+fn unwrap_self_type(self_type: &mut Type) {
+    loop {
+        let inner = match &*self_type {
+            Type::Group(group) => (*group.elem).clone(),
+            Type::Paren(paren) => (*paren.elem).clone(),
+            _ => return,
+        };
+        *self_type = inner;
     }
 }
 
-fn replace_self_type(self_type: &mut Type) {
+fn replace_self_type(self_type: &mut Type) -> syn::Result<()> {
     match self_type {
-        Type::Path(path) => replace_last_path_segment(&mut path.path),
+        Type::Path(path) => {
+            replace_last_path_segment(&mut path.path);
+            Ok(())
+        }
         unsupported_self_ty => {
-            let self_ty_verbatim = quote! { #unsupported_self_ty };
-            panic!("`impl {self_ty_verbatim}` unsupported: self type can only be syn::Type::Path")
+            Err(ArchiveImplError::UnsupportedSelfType(unsupported_self_ty.clone()).into())
         }
     }
 }
 
-fn replace_last_path_segment(p: &mut syn::Path) {
-    let orig_ident = &p.segments.last().unwrap().ident;
-    let archived_name = format!("Archived{orig_ident}");
-    let archived_ident = syn::Ident::new(&archived_name, orig_ident.span());
-    p.segments.last_mut().unwrap().ident = archived_ident;
+// With `archived_self`, the archived type may have fewer generics than the
+// original. Params that no longer appear in the self type (or trait) would be
+// unconstrained, so they are removed along with any predicates that use them.
+fn prune_unused_generics(archived_impl: &mut ItemImpl) {
+    struct IdentCollector(Vec<Ident>);
+
+    impl<'ast> Visit<'ast> for IdentCollector {
+        fn visit_ident(&mut self, ident: &'ast Ident) {
+            self.0.push(ident.clone());
+        }
+    }
+
+    let mut used = IdentCollector(Vec::new());
+    used.visit_type(&archived_impl.self_ty);
+    if let Some((_, trait_path, _)) = &archived_impl.trait_ {
+        used.visit_path(trait_path);
+    }
+    let param_ident = |param: &GenericParam| match param {
+        GenericParam::Type(t) => t.ident.clone(),
+        GenericParam::Lifetime(l) => l.lifetime.ident.clone(),
+        GenericParam::Const(c) => c.ident.clone(),
+    };
+    let generics = &mut archived_impl.generics;
+    let (kept, removed): (Vec<_>, Vec<_>) = std::mem::take(&mut generics.params)
+        .into_iter()
+        .partition(|param| used.0.contains(&param_ident(param)));
+    generics.params = kept.into_iter().collect();
+    let removed: Vec<_> = removed.iter().map(param_ident).collect();
+
+    let Some(where_clause) = &mut generics.where_clause else {
+        return;
+    };
+    where_clause.predicates = std::mem::take(&mut where_clause.predicates)
+        .into_iter()
+        .filter(|predicate| {
+            let mut mentioned = IdentCollector(Vec::new());
+            mentioned.visit_where_predicate(predicate);
+            !mentioned.0.iter().any(|ident| removed.contains(ident))
+        })
+        .collect();
+}
+
+/// A blanket `impl<T> Trait for T` has no archived counterpart to rename to.
+fn reject_blanket_self_type(self_ty: &Type, generics: &Generics) -> syn::Result<()> {
+    let Type::Path(TypePath { qself: None, path }) = self_ty else {
+        return Ok(());
+    };
+    let Some(ident) = path.get_ident() else {
+        return Ok(());
+    };
+    if generics.type_params().any(|param| param.ident == *ident) {
+        return Err(ArchiveImplError::BlanketImpl(self_ty.clone()).into());
+    }
+    Ok(())
+}
+
+fn replace_trait(archived_trait: Path, archived_impl: &mut ItemImpl) -> syn::Result<()> {
+    match &mut archived_impl.trait_ {
+        Some((_, trait_path, _)) => {
+            *trait_path = archived_trait;
+            Ok(())
+        }
+        None => Err(ArchiveImplError::WrongImplKind {
+            tokens: quote! { #archived_trait },
+            argument: "archived_trait",
+            expected: "a trait `impl`",
+        }
+        .into()),
+    }
+}
+
+fn replace_last_path_segment(p: &mut syn::Path) {
+    let orig_ident = &p.segments.last().unwrap().ident;
+    let archived_name = format!("Archived{orig_ident}");
+    let archived_ident = syn::Ident::new(&archived_name, orig_ident.span());
+    p.segments.last_mut().unwrap().ident = archived_ident;
+}
+
+// Links each generated method to the original, like "See also [`Foo::len`].".
+// Generic arguments aren't allowed in intra-doc links, so they're dropped.
+fn add_doc_links(orig_self_ty: &Type, items: &mut [ImplItem]) {
+    let mut self_ty = orig_self_ty.clone();
+    unwrap_self_type(&mut self_ty);
+    let Type::Path(TypePath { path, .. }) = self_ty else {
+        return;
+    };
+    let leading_colon = if path.leading_colon.is_some() {
+        "::"
+    } else {
+        ""
+    };
+    let segments: Vec<_> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+    let type_path = format!("{leading_colon}{}", segments.join("::"));
+
+    for item in items {
+        let ImplItem::Fn(fn_item) = item else {
+            continue;
+        };
+        let method = &fn_item.sig.ident;
+        let doc = format!(" See also [`{type_path}::{method}`].");
+        if fn_item.attrs.iter().any(|attr| attr.path().is_ident("doc")) {
+            fn_item.attrs.push(parse_quote! { #[doc = ""] });
+        }
+        fn_item.attrs.push(parse_quote! { #[doc = #doc] });
+    }
+}
+
+// Renames the self type where it prefixes a path in a method body, like
+// `Foo::helper`, or `crate::Foo::<T>::helper` for `impl crate::Foo<T>`.
+fn rewrite_self_calls(orig_self_ty: &Type, items: &mut [ImplItem]) {
+    let mut self_ty = orig_self_ty.clone();
+    unwrap_self_type(&mut self_ty);
+    let Type::Path(TypePath {
+        qself: None,
+        path: self_path,
+    }) = self_ty
+    else {
+        return;
+    };
+
+    for item in items {
+        let ImplItem::Fn(fn_item) = item else {
+            continue;
+        };
+        let mut rewriter = SelfCallRewriter {
+            self_path: &self_path,
+            qualified: false,
+        };
+        rewriter.visit_block_mut(&mut fn_item.block);
+    }
+}
+
+struct SelfCallRewriter<'a> {
+    self_path: &'a Path,
+    // Set between a `QSelf` and the path it qualifies, like `Trait::f` in
+    // `<Foo as Trait>::f`, which is left alone.
+    qualified: bool,
+}
+
+impl SelfCallRewriter<'_> {
+    // The index of the segment naming the self type, if `path` goes through
+    // it: either a bare `Foo::...`, or the same prefix as the self type, like
+    // `crate::Foo::...` for `impl crate::Foo`.
+    fn self_segment(&self, path: &Path) -> Option<usize> {
+        let self_len = self.self_path.segments.len();
+        let name = &self.self_path.segments.last().unwrap().ident;
+        if path.segments.len() < 2 {
+            return None;
+        }
+        if path.leading_colon.is_none() && path.segments[0].ident == *name {
+            return Some(0);
+        }
+        let same_prefix = path.segments.len() > self_len
+            && path.leading_colon.is_some() == self.self_path.leading_colon.is_some()
+            && path
+                .segments
+                .iter()
+                .zip(&self.self_path.segments)
+                .all(|(segment, self_segment)| segment.ident == self_segment.ident);
+        same_prefix.then_some(self_len - 1)
+    }
+}
+
+// Visits every path in the body, including patterns (`Foo::A(x) =>`), struct
+// expressions (`Foo::A { .. }`) and types (`let x: Foo::Assoc`).
+impl VisitMut for SelfCallRewriter<'_> {
+    fn visit_qself_mut(&mut self, qself: &mut QSelf) {
+        visit_mut::visit_qself_mut(self, qself);
+        // syn visits the qualified path right after its `QSelf`.
+        self.qualified = true;
+    }
+
+    fn visit_path_mut(&mut self, path: &mut Path) {
+        let qualified = std::mem::take(&mut self.qualified);
+        visit_mut::visit_path_mut(self, path);
+        if qualified {
+            return;
+        }
+        if let Some(index) = self.self_segment(path) {
+            let segment = &mut path.segments[index];
+            segment.ident = format_ident!("Archived{}", segment.ident);
+        }
+    }
+}
+
+fn add_size_helper(archived_impl: &mut ItemImpl) -> syn::Result<()> {
+    if let Some((_, trait_path, _)) = &archived_impl.trait_ {
+        return Err(ArchiveImplError::WrongImplKind {
+            tokens: quote! { #trait_path },
+            argument: "gen_size_helper",
+            expected: "an inherent `impl`",
+        }
+        .into());
+    }
+    archived_impl.items.push(parse_quote! {
+        /// The size of the archived type in bytes.
+        pub const fn archived_size() -> usize {
+            ::core::mem::size_of::<Self>()
+        }
+    });
+    Ok(())
+}
+
+// Renames the types named in `transform_self_args` wherever they appear in the
+// generic arguments of the self type, like `Wrapper<Foo>` to
+// `ArchivedWrapper<ArchivedFoo>`.
+fn rename_self_args(names: &[Ident], self_type: &mut Type) {
+    let Type::Path(path) = self_type else { return };
+    let last_segment = path.path.segments.last_mut().unwrap();
+    SelfArgRenamer { names }.visit_path_arguments_mut(&mut last_segment.arguments);
+}
+
+struct SelfArgRenamer<'a> {
+    names: &'a [Ident],
+}
+
+impl VisitMut for SelfArgRenamer<'_> {
+    fn visit_type_path_mut(&mut self, p: &mut TypePath) {
+        visit_mut::visit_type_path_mut(self, p);
+        if p.qself.is_none() && self.names.contains(&p.path.segments.last().unwrap().ident) {
+            replace_last_path_segment(&mut p.path);
+        }
+    }
 }
 
-// Augments the where clause of each method with an `archive_method` attribute.
-fn augment_methods(augmented_items: &mut [ImplItem]) -> syn::Result<()> {
-    for item in augmented_items {
-        if let ImplItem::Fn(fn_item) = item {
-            augment_method(fn_item)?;
+// Augments the where clause of each method with an `archive_method` attribute,
+// and drops the methods that should not be copied to the archived impl.
+fn augment_methods(
+    augmented_items: &mut Vec<ImplItem>,
+    impl_args: &Arguments,
+    orig_impl: &ItemImpl,
+) -> syn::Result<()> {
+    for name in &impl_args.transform_methods {
+        let found = augmented_items
+            .iter()
+            .any(|item| matches!(item, ImplItem::Fn(f) if f.sig.ident == *name));
+        if !found {
+            return Err(ArchiveImplError::UnknownTransformMethod(name.clone()).into());
         }
     }
+
+    let mut kept_items = Vec::with_capacity(augmented_items.len());
+    for mut item in augmented_items.drain(..) {
+        if let ImplItem::Fn(fn_item) = &mut item {
+            if !augment_method(fn_item, impl_args, orig_impl)? {
+                continue;
+            }
+        }
+        kept_items.push(item);
+    }
+    *augmented_items = kept_items;
     Ok(())
 }
 
-fn augment_method(fn_item: &mut ImplItemFn) -> syn::Result<()> {
-    let mut args_builder = ArgumentsBuilder::default();
+// Returns `false` if the method should be omitted from the archived impl.
+fn augment_method(
+    fn_item: &mut ImplItemFn,
+    impl_args: &Arguments,
+    orig_impl: &ItemImpl,
+) -> syn::Result<bool> {
+    let mut args_builder = ArgumentsBuilder::new(ArgumentScope::Method);
+    args_builder.no_archive_bounds = impl_args.no_archive_bounds;
+    args_builder.bound_trait = impl_args.bound_trait.clone();
+    args_builder.type_mappings = impl_args.type_mappings.clone();
+    if impl_args.transform_methods.contains(&fn_item.sig.ident) {
+        for param in &impl_args.transform_params {
+            args_builder
+                .transform_params
+                .insert(param.ident.clone(), param.projection.clone());
+        }
+    }
+    let mut annotated = false;
     for attr in &fn_item.attrs {
         if !attr.path().is_ident("archive_method") {
             continue;
         }
+        annotated = true;
 
         match &attr.meta {
+            Meta::Path(_) => (),
             Meta::List(meta_list) => {
                 args_builder.try_add_metas_token_stream(meta_list.tokens.clone().into())?;
             }
             unsupported_meta => {
-                let meta_verbatim = quote! { #unsupported_meta };
-                panic!(
-                    "Unsupported meta `{meta_verbatim}`: meta can only be structure list `archive_method(...)`"
-                );
+                return Err(ArchiveImplError::UnsupportedMeta {
+                    meta: unsupported_meta.clone(),
+                    expected: "`archive_method` or `archive_method(...)`",
+                }
+                .into());
             }
         }
     }
-    let args = args_builder.build();
-    transform_generics(&args.transform_params, &mut fn_item.sig.generics);
+    if args_builder.transform_bounds_from_impl {
+        for param in &impl_args.transform_params {
+            args_builder
+                .transform_params
+                .entry(param.ident.clone())
+                .or_insert_with(|| param.projection.clone());
+        }
+    }
+    let args = args_builder.build()?;
+    let inherent = orig_impl.trait_.is_none();
+    if should_skip_method(&args, annotated, impl_args, inherent, &fn_item.sig) {
+        return Ok(false);
+    }
+    // Method bounds can refer to params declared on the method or the impl.
+    let scopes = [&fn_item.sig.generics, &orig_impl.generics];
+    validate_transform_params(&args.transform_params, &scopes)?;
+    validate_transform_params(&args.transform_return_params, &scopes)?;
+    validate_transform_params(&args.transform_input_params, &scopes)?;
+    transform_generics(
+        &args.transform_params,
+        &args.preserve_bounds,
+        &mut fn_item.sig.generics,
+    )?;
+    transform_input_types(
+        &args.transform_input_params,
+        &args.type_mappings,
+        &mut fn_item.sig.inputs,
+    );
+    transform_return_type(
+        &args.transform_return_params,
+        &args.type_mappings,
+        &mut fn_item.sig.output,
+    );
     add_bounds_to_where_clause(args.add_bounds, &mut fn_item.sig.generics.where_clause);
-    Ok(())
+    // Added after the transform, so their bounds are used as written.
+    // Lifetimes must come before the other params.
+    for param in args.add_generics {
+        let generics = &mut fn_item.sig.generics;
+        if let GenericParam::Lifetime(_) = param {
+            generics.params.insert(generics.lifetimes().count(), param);
+        } else {
+            generics.params.push(param);
+        }
+    }
+    if let Some(archived_body) = args.archived_body {
+        fn_item.block = archived_body;
+    }
+    if let Some(feature) = args.cfg_archived {
+        fn_item
+            .attrs
+            .push(parse_quote! { #[cfg(feature = #feature)] });
+    }
+    if let Some(vis) = args.vis {
+        fn_item.vis = vis;
+    }
+    if impl_args.no_mut {
+        remove_mut_receiver(&mut fn_item.sig);
+    }
+    if impl_args.track_caller
+        && !fn_item
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("track_caller"))
+    {
+        fn_item.attrs.push(parse_quote! { #[track_caller] });
+    }
+    Ok(true)
 }
 
-fn transform_generics(replace_params: &[Ident], generics: &mut Generics) {
-    struct TypeReplacer<'a> {
-        replace_params: &'a [Ident],
-        archived_assoc: Ident,
-    }
-    impl<'a> VisitMut for TypeReplacer<'a> {
-        fn visit_type_path_mut(&mut self, p: &mut TypePath) {
-            for r in self.replace_params {
-                // Only modify type paths where the first segment matches the
-                // type parameter.
-                if p.path.segments.first().map(|seg| &seg.ident) == Some(r) {
-                    p.path
-                        .segments
-                        .insert(1, self.archived_assoc.clone().into());
-                }
+fn should_skip_method(
+    args: &Arguments,
+    annotated: bool,
+    impl_args: &Arguments,
+    inherent: bool,
+    sig: &Signature,
+) -> bool {
+    if args.skip || (impl_args.archived_methods_only && !annotated) {
+        return true;
+    }
+    if args.keep {
+        return false;
+    }
+    (inherent && is_constructor(sig))
+        || (impl_args.skip_mut_methods && has_mut_receiver(sig))
+        || (impl_args.skip_by_value_self && has_by_value_receiver(sig))
+}
 
-                if let Some(qself) = &mut p.qself {
-                    self.visit_qself_mut(qself);
-                }
-            }
+fn has_mut_receiver(sig: &Signature) -> bool {
+    let Some(receiver) = sig.receiver() else {
+        return false;
+    };
+    matches!(&*receiver.ty, Type::Reference(r) if r.mutability.is_some())
+}
+
+// Turns `&mut self` (or `self: &mut Self`) into a shared receiver.
+fn remove_mut_receiver(sig: &mut Signature) {
+    let Some(FnArg::Receiver(receiver)) = sig.inputs.first_mut() else {
+        return;
+    };
+    let Type::Reference(ty) = &mut *receiver.ty else {
+        return;
+    };
+    if ty.mutability.take().is_some() && receiver.reference.is_some() {
+        receiver.mutability = None;
+    }
+}
+
+fn has_by_value_receiver(sig: &Signature) -> bool {
+    let Some(receiver) = sig.receiver() else {
+        return false;
+    };
+    matches!(&*receiver.ty, Type::Path(p) if p.qself.is_none() && p.path.is_ident("Self"))
+}
+
+// Heuristic for associated functions like `fn new() -> Self`. Without a
+// receiver, the only way to produce an owned `Self` is to construct one, and
+// the archived type can't be constructed like the original. References to
+// `Self` (like `fn pick(a: &Self, b: &Self) -> &Self`) work on both types.
+fn is_constructor(sig: &Signature) -> bool {
+    if sig.receiver().is_some() {
+        return false;
+    }
+    let ReturnType::Type(_, output) = &sig.output else {
+        return false;
+    };
+    is_self(output) || is_wrapped_self(output)
+}
+
+fn is_self(ty: &Type) -> bool {
+    matches!(ty, Type::Path(p) if p.qself.is_none() && p.path.is_ident("Self"))
+}
+
+// `Option<Self>` or `Result<Self, E>`.
+fn is_wrapped_self(ty: &Type) -> bool {
+    let Type::Path(p) = ty else { return false };
+    let Some(last) = p.path.segments.last() else {
+        return false;
+    };
+    if p.qself.is_some() || (last.ident != "Option" && last.ident != "Result") {
+        return false;
+    }
+    let PathArguments::AngleBracketed(args) = &last.arguments else {
+        return false;
+    };
+    matches!(args.args.first(), Some(GenericArgument::Type(ty)) if is_self(ty))
+}
+
+// Emits a warning for each constructor left out of the archived impl by
+// `is_constructor`, unless the method says what to do with `archive_method`.
+// Stable proc macros can't emit warnings directly, so this uses a deprecated
+// item instead.
+fn skipped_constructor_warnings(orig_impl: &ItemImpl) -> TokenStream2 {
+    let warnings = orig_impl.items.iter().filter_map(|item| {
+        let ImplItem::Fn(fn_item) = item else {
+            return None;
+        };
+        let annotated = fn_item
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("archive_method"));
+        if annotated || !is_constructor(&fn_item.sig) {
+            return None;
+        }
+        let name = &fn_item.sig.ident;
+        let note = format!(
+            "`{name}` returns `Self` without a receiver, so it is left out of the archived impl; \
+             add `#[archive_method(skip)]` to silence this, or `#[archive_method(keep)]` to copy it"
+        );
+        Some(quote_spanned! {name.span()=>
+            const _: () = {
+                #[deprecated(note = #note)]
+                struct SkippedConstructor;
+                let _ = SkippedConstructor;
+            };
+        })
+    });
+    quote! { #(#warnings)* }
+}
+
+fn validate_transform_params(params: &[TransformParam], scopes: &[&Generics]) -> syn::Result<()> {
+    for TransformParam { ident: param, .. } in params {
+        let declared = scopes
+            .iter()
+            .flat_map(|generics| generics.type_params())
+            .any(|type_param| &type_param.ident == param);
+        if !declared {
+            return Err(ArchiveImplError::UndeclaredTransformParam(param.clone()).into());
         }
     }
+    Ok(())
+}
 
+fn transform_generics(
+    replace_params: &[TransformParam],
+    preserve_bounds: &[WherePredicate],
+    generics: &mut Generics,
+) -> syn::Result<()> {
     // We must normalize the generics to put all bounds into the where clause.
     // For example, we can't change impl<T: ...> to impl<T::Archived: ...>.
     normalize_generics(generics);
 
-    let Some(where_clause) = &mut generics.where_clause else { return };
+    let Some(where_clause) = &mut generics.where_clause else {
+        return match preserve_bounds.first() {
+            Some(unmatched) => Err(unmatched_preserve_bound(unmatched)),
+            None => Ok(()),
+        };
+    };
 
-    TypeReplacer {
-        replace_params,
-        archived_assoc: Ident::new("Archived", Span::call_site()),
+    let mut preserved = split_preserved_bounds(where_clause, preserve_bounds)?;
+    preserved.extend(split_archive_bounds(where_clause, replace_params));
+    TypeReplacer::new(replace_params).visit_where_clause_mut(where_clause);
+    where_clause.predicates.extend(preserved);
+    Ok(())
+}
+
+// Moves bounds like `T: Archive<Archived = U>` out of the where clause, since
+// they describe the transformed param itself. Transforming them would give
+// `T::Archived: Archive<Archived = U>`.
+fn split_archive_bounds(
+    where_clause: &mut WhereClause,
+    replace_params: &[TransformParam],
+) -> Vec<WherePredicate> {
+    let mut moved_predicates = Vec::new();
+    for predicate in where_clause.predicates.iter_mut() {
+        let WherePredicate::Type(predicate) = predicate else {
+            continue;
+        };
+        let Type::Path(TypePath { qself: None, path }) = &predicate.bounded_ty else {
+            continue;
+        };
+        let Some(param) = replace_params
+            .iter()
+            .find(|param| path.is_ident(&param.ident))
+        else {
+            continue;
+        };
+        let archive_ident = &param.bound_trait.segments.last().unwrap().ident;
+        let (moved, keep): (Vec<_>, Vec<_>) = std::mem::take(&mut predicate.bounds)
+            .into_iter()
+            .partition(|bound| match bound {
+                TypeParamBound::Trait(bound) => {
+                    bound.path.segments.last().unwrap().ident == *archive_ident
+                }
+                _ => false,
+            });
+        predicate.bounds = keep.into_iter().collect();
+        if !moved.is_empty() {
+            let lifetimes = &predicate.lifetimes;
+            let bounded_ty = &predicate.bounded_ty;
+            moved_predicates.push(parse_quote! { #lifetimes #bounded_ty: #(#moved)+* });
+        }
+    }
+    where_clause.predicates = std::mem::take(&mut where_clause.predicates)
+        .into_iter()
+        .filter(|predicate| !matches!(predicate, WherePredicate::Type(p) if p.bounds.is_empty()))
+        .collect();
+    moved_predicates
+}
+
+// Moves each bound listed in `preserve_bounds` out of the where clause, so it
+// can be added back after the rest is transformed. A predicate like
+// `T: Clone + Send` under `preserve_bounds(T: Send)` is split in two.
+fn split_preserved_bounds(
+    where_clause: &mut WhereClause,
+    preserve_bounds: &[WherePredicate],
+) -> syn::Result<Vec<WherePredicate>> {
+    let tokens = |t: &dyn quote::ToTokens| t.to_token_stream().to_string();
+
+    let mut preserved = Vec::new();
+    for preserve in preserve_bounds {
+        let WherePredicate::Type(preserve) = preserve else {
+            return Err(ArchiveImplError::UnsupportedPreserveBound(preserve.clone()).into());
+        };
+        let preserve_ty = tokens(&preserve.bounded_ty);
+        let mut found = false;
+        for predicate in where_clause.predicates.iter_mut() {
+            let WherePredicate::Type(predicate) = predicate else {
+                continue;
+            };
+            if tokens(&predicate.bounded_ty) != preserve_ty
+                || tokens(&predicate.lifetimes) != tokens(&preserve.lifetimes)
+            {
+                continue;
+            }
+            let (keep, moved): (Vec<_>, Vec<_>) = std::mem::take(&mut predicate.bounds)
+                .into_iter()
+                .partition(|bound| !preserve.bounds.iter().any(|b| tokens(b) == tokens(bound)));
+            predicate.bounds = keep.into_iter().collect();
+            if !moved.is_empty() {
+                found = true;
+                let lifetimes = &predicate.lifetimes;
+                let bounded_ty = &predicate.bounded_ty;
+                preserved.push(parse_quote! { #lifetimes #bounded_ty: #(#moved)+* });
+            }
+        }
+        if !found {
+            return Err(unmatched_preserve_bound(&WherePredicate::Type(
+                preserve.clone(),
+            )));
+        }
+    }
+
+    // Drop predicates that had all of their bounds preserved.
+    where_clause.predicates = std::mem::take(&mut where_clause.predicates)
+        .into_iter()
+        .filter(|predicate| !matches!(predicate, WherePredicate::Type(p) if p.bounds.is_empty()))
+        .collect();
+    Ok(preserved)
+}
+
+fn unmatched_preserve_bound(preserve: &WherePredicate) -> syn::Error {
+    ArchiveImplError::UnmatchedPreserveBound(preserve.clone()).into()
+}
+
+// Only the definition is transformed. The generics and where clause of a
+// generic associated type must match the trait, so they're left alone.
+fn transform_assoc_types(replace_params: &[TransformParam], items: &mut [ImplItem]) {
+    for item in items {
+        if let ImplItem::Type(type_item) = item {
+            TypeReplacer::new(replace_params).visit_type_mut(&mut type_item.ty);
+        }
+    }
+}
+
+fn transform_input_types(
+    replace_params: &[TransformParam],
+    mappings: &[TypeMapping],
+    inputs: &mut Punctuated<FnArg, Token![,]>,
+) {
+    // Argument types are only transformed by `transform_all`.
+    if replace_params.is_empty() {
+        return;
+    }
+    for input in inputs {
+        if let FnArg::Typed(pat_type) = input {
+            TypeReplacer::new(replace_params)
+                .with_mappings(mappings)
+                .visit_type_mut(&mut pat_type.ty);
+        }
+    }
+}
+
+fn transform_return_type(
+    replace_params: &[TransformParam],
+    mappings: &[TypeMapping],
+    output: &mut ReturnType,
+) {
+    TypeReplacer::new(replace_params)
+        .with_mappings(mappings)
+        .visit_return_type_mut(output);
+}
+
+// Rewrites each `T` in `replace_params` to `T::Archived`, or whatever
+// projection was configured for `T`.
+struct TypeReplacer<'a> {
+    replace_params: &'a [TransformParam],
+    mappings: &'a [TypeMapping],
+}
+
+impl<'a> TypeReplacer<'a> {
+    fn new(replace_params: &'a [TransformParam]) -> Self {
+        Self {
+            replace_params,
+            mappings: &[],
+        }
+    }
+
+    fn with_mappings(self, mappings: &'a [TypeMapping]) -> Self {
+        Self { mappings, ..self }
+    }
+
+    // Only type paths where the first segment is a bare type parameter refer to
+    // the parameter. A qualified or absolute path (`<X as T>::Y`, `::T`) or a
+    // segment with generic arguments (`T<A>::Y`) must be some other item that
+    // happens to share the name.
+    fn find_param(&self, p: &TypePath) -> Option<&'a TransformParam> {
+        if p.qself.is_some() || p.path.leading_colon.is_some() {
+            return None;
+        }
+        let first = p.path.segments.first()?;
+        if !first.arguments.is_none() {
+            return None;
+        }
+        self.replace_params
+            .iter()
+            .find(|param| param.ident == first.ident)
+    }
+}
+
+impl<'a> VisitMut for TypeReplacer<'a> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        // An exact match is replaced as written, without transforming the
+        // params inside it.
+        let mapping = self.mappings.iter().find(|m| m.matches_type(ty));
+        if let Some(TypeMapping::Type { to, .. }) = mapping {
+            *ty = (**to).clone();
+            return;
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+
+    fn visit_type_path_mut(&mut self, p: &mut TypePath) {
+        // Params can also be nested in the `qself` or generic arguments, like
+        // `<T as Trait>::Assoc` or `Vec<T>`. These are visited first so the
+        // rewritten path isn't visited again.
+        visit_mut::visit_type_path_mut(self, p);
+
+        if p.qself.is_none() {
+            let mapping = self.mappings.iter().find(|m| m.matches_path(&p.path));
+            if let Some(TypeMapping::Path { to, .. }) = mapping {
+                let arguments = p.path.segments.last().unwrap().arguments.clone();
+                p.path = to.clone();
+                p.path.segments.last_mut().unwrap().arguments = arguments;
+                return;
+            }
+        }
+
+        let Some(param) = self.find_param(p) else {
+            return;
+        };
+        let projection = &param.projection;
+        if projection.len() == 1 {
+            // The param is always the first segment, so the projection goes
+            // right after it, e.g. `T::Assoc` becomes `T::Archived::Assoc`.
+            p.path.segments.insert(1, projection[0].clone());
+        } else {
+            // `T::Inner::Archived` would be ambiguous, so it must be written
+            // as `<T::Inner as Archive>::Archived`.
+            let ident = &param.ident;
+            let bound_trait = &param.bound_trait;
+            let prefix = projection.iter().take(projection.len() - 1);
+            let last = projection.last().unwrap();
+            let rest = p.path.segments.iter().skip(1);
+            *p = parse_quote! { <#ident #(:: #prefix)* as #bound_trait>::#last #(:: #rest)* };
+        }
     }
-    .visit_where_clause_mut(where_clause);
 }
 
 fn normalize_generics(generics: &mut Generics) {
+    strip_defaults(generics);
+
     let mut move_predicates = Vec::<WherePredicate>::new();
     for param in &mut generics.params {
         // Maybe a little hacky. All type params with non-empty bounds are also
         // valid predicates.
         match param {
-            GenericParam::Type(t_param) => {
-                if !t_param.bounds.is_empty() {
-                    move_predicates.push(parse_quote!(#t_param));
-                    t_param.bounds.clear();
-                }
+            GenericParam::Type(t_param) if !t_param.bounds.is_empty() => {
+                move_predicates.push(parse_quote!(#t_param));
+                t_param.bounds.clear();
             }
-            GenericParam::Lifetime(lt_param) => {
-                if !lt_param.bounds.is_empty() {
-                    move_predicates.push(parse_quote!(#lt_param));
-                    lt_param.bounds.clear();
-                }
+            GenericParam::Lifetime(lt_param) if !lt_param.bounds.is_empty() => {
+                move_predicates.push(parse_quote!(#lt_param));
+                lt_param.bounds.clear();
             }
             _ => (),
         }
@@ -367,12 +2333,32 @@ fn normalize_generics(generics: &mut Generics) {
         .extend(move_predicates);
 }
 
+// Defaults aren't allowed on impl generics (`invalid_type_param_default`), but
+// they're only linted. They'd also break `T: Bound = Default` as a predicate,
+// and a default mentioning a transformed param shouldn't be transformed, so
+// they're dropped before anything else.
+fn strip_defaults(generics: &mut Generics) {
+    for param in &mut generics.params {
+        match param {
+            GenericParam::Type(t_param) => {
+                t_param.eq_token = None;
+                t_param.default = None;
+            }
+            GenericParam::Const(c_param) => {
+                c_param.eq_token = None;
+                c_param.default = None;
+            }
+            GenericParam::Lifetime(_) => (),
+        }
+    }
+}
+
 fn add_bounds_to_where_clause(
     additional_bounds: Vec<WherePredicate>,
     clause: &mut Option<WhereClause>,
 ) {
     if let Some(clause) = clause {
-        clause.predicates.extend(additional_bounds.into_iter());
+        clause.predicates.extend(additional_bounds);
     } else if !additional_bounds.is_empty() {
         *clause = Some(parse_quote! { where #(#additional_bounds),* });
     }
@@ -380,36 +2366,241 @@ fn add_bounds_to_where_clause(
 
 fn parse_argument_metas(args: TokenStream, arg_lists: &mut Vec<Meta>) -> syn::Result<()> {
     let parser = Punctuated::<Meta, Token![,]>::parse_terminated;
-    arg_lists.extend(parser.parse(args)?.into_iter());
+    arg_lists.extend(parser.parse(args)?);
     Ok(())
 }
 
-fn parse_transform_bounds(meta: &Meta, transform_params: &mut HashSet<Ident>) -> syn::Result<()> {
+fn parse_transform_bounds(
+    meta: &Meta,
+    transform_params: &mut IndexMap<Ident, Projection>,
+) -> syn::Result<()> {
+    match meta {
+        Meta::List(meta_list) => {
+            let parser = Punctuated::<TransformParam, Token![,]>::parse_terminated;
+            for param in parser.parse(meta_list.tokens.clone().into())? {
+                transform_params.insert(param.ident, param.projection);
+            }
+            Ok(())
+        }
+        unsupported_meta => Err(unsupported_list_meta(unsupported_meta)),
+    }
+}
+
+fn parse_assoc(meta: &Meta, assoc: &mut Vec<(Ident, Ident)>) -> syn::Result<()> {
+    let Meta::List(meta_list) = meta else {
+        return Err(unsupported_list_meta(meta));
+    };
+    let mut entry_metas = Vec::new();
+    parse_argument_metas(meta_list.tokens.clone().into(), &mut entry_metas)?;
+    for entry_meta in entry_metas {
+        let param = match entry_meta.path().get_ident() {
+            Some(param) => param.clone(),
+            None => {
+                return Err(ArchiveImplError::UnknownOption {
+                    option: entry_meta.path().clone(),
+                    argument: "assoc",
+                }
+                .into())
+            }
+        };
+        assoc.push((param, parse_str_value(&entry_meta)?));
+    }
+    Ok(())
+}
+
+fn parse_flag(meta: &Meta) -> syn::Result<bool> {
+    match meta {
+        Meta::Path(_) => Ok(true),
+        Meta::NameValue(MetaNameValue {
+            value:
+                Expr::Lit(ExprLit {
+                    lit: Lit::Bool(lit_bool),
+                    ..
+                }),
+            ..
+        }) => Ok(lit_bool.value),
+        unsupported_meta => Err(ArchiveImplError::UnsupportedMeta {
+            meta: unsupported_meta.clone(),
+            expected: "a flag, optionally with `= true` or `= false`",
+        }
+        .into()),
+    }
+}
+
+fn parse_str_value<T: Parse>(meta: &Meta) -> syn::Result<T> {
+    parse_lit_str(meta)?.parse()
+}
+
+fn parse_lit_str(meta: &Meta) -> syn::Result<LitStr> {
+    match meta {
+        Meta::NameValue(MetaNameValue {
+            value:
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit_str),
+                    ..
+                }),
+            ..
+        }) => Ok(lit_str.clone()),
+        unsupported_meta => Err(ArchiveImplError::UnsupportedMeta {
+            meta: unsupported_meta.clone(),
+            expected: "a name with a string value, like `name = \"...\"`",
+        }
+        .into()),
+    }
+}
+
+fn parse_archived_body(meta: &Meta) -> syn::Result<Block> {
+    match meta {
+        Meta::NameValue(MetaNameValue {
+            value: Expr::Block(expr_block),
+            ..
+        }) if expr_block.attrs.is_empty() && expr_block.label.is_none() => {
+            Ok(expr_block.block.clone())
+        }
+        unsupported_meta => Err(ArchiveImplError::UnsupportedMeta {
+            meta: unsupported_meta.clone(),
+            expected: "`archived_body = { ... }`",
+        }
+        .into()),
+    }
+}
+
+fn parse_projection(meta: &Meta) -> syn::Result<Projection> {
+    match meta {
+        Meta::List(meta_list) if meta_list.path.is_ident("path") => {
+            let lit_str: LitStr = meta_list.parse_args()?;
+            lit_str.parse_with(Projection::parse_separated_nonempty)
+        }
+        unsupported_meta => Err(ArchiveImplError::UnsupportedMeta {
+            meta: unsupported_meta.clone(),
+            expected: "a projection like `path(\"...\")`",
+        }
+        .into()),
+    }
+}
+
+fn parse_ident_list(meta: &Meta, idents: &mut Vec<Ident>) -> syn::Result<()> {
     match meta {
         Meta::List(meta_list) => {
             let parser = Punctuated::<Ident, Token![,]>::parse_terminated;
-            transform_params.extend(parser.parse(meta_list.tokens.clone().into())?.into_iter());
+            idents.extend(parser.parse(meta_list.tokens.clone().into())?);
             Ok(())
         }
+        unsupported_meta => Err(unsupported_list_meta(unsupported_meta)),
+    }
+}
+
+fn parse_path_list(meta: &Meta, paths: &mut Vec<Path>) -> syn::Result<()> {
+    match meta {
+        Meta::List(meta_list) => {
+            let parser = Punctuated::<Path, Token![,]>::parse_terminated;
+            paths.extend(parser.parse(meta_list.tokens.clone().into())?);
+            Ok(())
+        }
+        unsupported_meta => Err(unsupported_list_meta(unsupported_meta)),
+    }
+}
+
+fn parse_gen_try_from(meta: &Meta) -> syn::Result<GenTryFrom> {
+    let mut options = GenTryFrom::default();
+    match meta {
+        Meta::Path(_) => (),
+        Meta::List(meta_list) => {
+            let mut option_metas = Vec::new();
+            parse_argument_metas(meta_list.tokens.clone().into(), &mut option_metas)?;
+            for option_meta in option_metas {
+                if option_meta.path().is_ident("deserializer") {
+                    options.deserializer = Some(parse_str_value(&option_meta)?);
+                } else {
+                    return Err(ArchiveImplError::UnknownOption {
+                        option: option_meta.path().clone(),
+                        argument: "gen_try_from",
+                    }
+                    .into());
+                }
+            }
+        }
         unsupported_meta => {
-            let meta_verbatim = quote! { #unsupported_meta };
-            panic!("Unsupported `{meta_verbatim}`: meta can only be structured list `transform_bounds(...)`");
+            return Err(ArchiveImplError::UnsupportedMeta {
+                meta: unsupported_meta.clone(),
+                expected: "`gen_try_from` or `gen_try_from(...)`",
+            }
+            .into());
         }
     }
+    Ok(options)
+}
+
+fn parse_gen_roundtrip_test(meta: &Meta) -> syn::Result<GenRoundtripTest> {
+    let missing_options = || ArchiveImplError::MissingOptions {
+        meta: meta.clone(),
+        argument: "gen_roundtrip_test",
+        options: "`sample` and `method`, like \
+                  `gen_roundtrip_test(sample = \"sample_foo\", method = \"len\")`",
+    };
+    let Meta::List(meta_list) = meta else {
+        return Err(missing_options().into());
+    };
+    let mut sample = None;
+    let mut method = None;
+    let mut option_metas = Vec::new();
+    parse_argument_metas(meta_list.tokens.clone().into(), &mut option_metas)?;
+    for option_meta in option_metas {
+        if option_meta.path().is_ident("sample") {
+            sample = Some(parse_str_value(&option_meta)?);
+        } else if option_meta.path().is_ident("method") {
+            method = Some(parse_str_value(&option_meta)?);
+        } else {
+            return Err(ArchiveImplError::UnknownOption {
+                option: option_meta.path().clone(),
+                argument: "gen_roundtrip_test",
+            }
+            .into());
+        }
+    }
+    match (sample, method) {
+        (Some(sample), Some(method)) => Ok(GenRoundtripTest { sample, method }),
+        _ => Err(missing_options().into()),
+    }
 }
 
 fn parse_add_bounds(meta: &Meta, add_bounds: &mut Vec<WherePredicate>) -> syn::Result<()> {
     match meta {
         Meta::List(meta_list) => {
             let parser = Punctuated::<WherePredicate, Token![,]>::parse_terminated;
-            add_bounds.extend(parser.parse(meta_list.tokens.clone().into())?.into_iter());
+            add_bounds.extend(parser.parse(meta_list.tokens.clone().into())?);
             Ok(())
         }
-        unsupported_meta => {
-            let meta_verbatim = quote! { #unsupported_meta };
-            panic!(
-                "Unsupported `{meta_verbatim}`: meta can only be structured list `add_bounds(...)`"
-            );
+        unsupported_meta => Err(unsupported_list_meta(unsupported_meta)),
+    }
+}
+
+fn parse_map_type(meta: &Meta, type_mappings: &mut Vec<TypeMapping>) -> syn::Result<()> {
+    match meta {
+        Meta::List(meta_list) => {
+            let parser = Punctuated::<TypeMapping, Token![,]>::parse_terminated;
+            type_mappings.extend(parser.parse(meta_list.tokens.clone().into())?);
+            Ok(())
         }
+        unsupported_meta => Err(unsupported_list_meta(unsupported_meta)),
     }
 }
+
+fn parse_add_generic(meta: &Meta, add_generics: &mut Vec<GenericParam>) -> syn::Result<()> {
+    match meta {
+        Meta::List(meta_list) => {
+            let parser = Punctuated::<GenericParam, Token![,]>::parse_terminated;
+            add_generics.extend(parser.parse(meta_list.tokens.clone().into())?);
+            Ok(())
+        }
+        unsupported_meta => Err(unsupported_list_meta(unsupported_meta)),
+    }
+}
+
+fn unsupported_list_meta(meta: &Meta) -> syn::Error {
+    ArchiveImplError::UnsupportedMeta {
+        meta: meta.clone(),
+        expected: "a structured list, like `name(...)`",
+    }
+    .into()
+}
@@ -0,0 +1,18 @@
+#![deny(non_snake_case)]
+
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo {
+    value: u32,
+}
+
+#[archive_impl(allow_lints(non_snake_case))]
+impl Foo {
+    pub fn Value(&self) -> u32 {
+        self.value
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use rkyv_impl::archive_method;
+
+pub struct Foo;
+
+impl Foo {
+    #[archive_method]
+    pub const N: usize = 1;
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use rkyv::Archive;
+use rkyv_impl::{archive_impl, archive_method};
+
+macro_rules! len_method {
+    () => {
+        pub fn len(&self) -> usize {
+            self.elements.len()
+        }
+    };
+}
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+#[archive_impl]
+impl Foo {
+    #[archive_method(skip)]
+    len_method!();
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+// The copied body keeps its spans, so the error points at `push` below.
+#[archive_impl]
+impl Foo {
+    pub fn push(&mut self, value: u32) {
+        self.elements.push(value);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+mod foo {
+    use rkyv::Archive;
+    use rkyv_impl::{archive_impl, archive_method};
+
+    #[derive(Archive)]
+    pub struct Foo {
+        value: u32,
+    }
+
+    #[archive_impl]
+    impl Foo {
+        #[archive_method(vis = "")]
+        pub fn value(&self) -> u32 {
+            self.value
+        }
+    }
+}
+
+fn call_archived(foo: &foo::ArchivedFoo) -> u32 {
+    foo.value()
+}
+
+fn main() {}
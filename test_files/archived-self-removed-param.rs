@@ -0,0 +1,21 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    value: T,
+}
+
+// `T` is removed from the generated `impl`, so the method can't use it.
+#[archive_impl(archived_self = "ArchivedBar")]
+impl<T> Foo<T> {
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+pub struct ArchivedBar {
+    value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use rkyv_impl::archive_impl;
+
+pub trait Describe {
+    fn describe(&self) -> &'static str;
+}
+
+#[archive_impl]
+impl<T> Describe for T {
+    fn describe(&self) -> &'static str {
+        "anything"
+    }
+}
+
+fn main() {}
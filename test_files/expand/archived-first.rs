@@ -0,0 +1,15 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    value: u32,
+}
+
+// `ArchivedFoo`'s impl comes before `Foo`'s.
+#[archive_impl(archived_first)]
+impl Foo {
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
@@ -0,0 +1,15 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    value: u32,
+}
+
+// Everything generated for `ArchivedFoo` is gated, and the original isn't.
+#[archive_impl(cfg_archived = "rkyv", gen_hash = "key")]
+impl Foo {
+    pub fn key(&self) -> u32 {
+        self.value
+    }
+}
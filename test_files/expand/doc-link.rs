@@ -0,0 +1,37 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+// A method with docs gets an empty line before the link.
+#[archive_impl(doc_link)]
+impl Foo {
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
+
+#[derive(Archive)]
+pub struct Bar<T> {
+    elements: Vec<T>,
+}
+
+// The link leaves out the generic arguments.
+#[archive_impl(doc_link, add_bounds(T: Archive))]
+impl<T> Bar<T> {
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
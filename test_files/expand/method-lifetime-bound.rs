@@ -0,0 +1,19 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    value: T,
+}
+
+// `'a` stays on the method, and `T: 'a` becomes `T::Archived: 'a`.
+#[archive_impl(add_bounds(T: Archive))]
+impl<T> Foo<T> {
+    #[archive_method(transform_bounds(T), transform_return(T))]
+    pub fn borrow_checked<'a>(&'a self) -> &'a T
+    where
+        T: 'a,
+    {
+        &self.value
+    }
+}
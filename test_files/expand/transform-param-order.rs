@@ -0,0 +1,22 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<A, B, C, D> {
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+}
+
+// The `Archive` bounds are added in the order the params are listed.
+#[archive_impl(transform_bounds(D, B, C, A))]
+impl<A, B, C, D> Foo<A, B, C, D>
+where
+    A: Clone,
+    B: Clone,
+    C: Clone,
+    D: Clone,
+{
+    pub fn a(&self) {}
+}
@@ -0,0 +1,23 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+pub trait Container {
+    type Item;
+}
+
+#[derive(Archive)]
+pub struct Foo<T, U> {
+    value: T,
+    items: U,
+}
+
+// `U::Item` is left alone, since only `T` is transformed.
+#[archive_impl(transform_bounds(T), add_bounds(U: Archive))]
+impl<T, U> Foo<T, U>
+where
+    T: Clone,
+    U: Container,
+    U::Item: Clone,
+{
+    pub fn a(&self) {}
+}
@@ -0,0 +1,22 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+fn sample_foo() -> Foo {
+    Foo {
+        elements: vec![1, 2, 3],
+    }
+}
+
+#[archive_impl(gen_roundtrip_test(sample = "sample_foo"))]
+impl Foo {
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo<T, U> {
+    first: Vec<T>,
+    second: Vec<U>,
+}
+
+// Only the original impl is rejected for its default. The generated impl drops
+// `= T` instead of transforming it into `= T::Archived`.
+#[archive_impl(transform_bounds(T), add_bounds(U: Archive))]
+impl<T: PartialEq, U: Clone = T> Foo<T, U> {
+    pub fn lens(&self) -> (usize, usize) {
+        (self.first.len(), self.second.len())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, Clone)]
+pub struct Foo {
+    value: u32,
+}
+
+pub trait Value {
+    fn value(&self) -> u32;
+}
+
+#[archive_impl(keep_self_wrapper)]
+impl Value for std::borrow::Cow<'_, Foo> {
+    fn value(&self) -> u32 {
+        self.as_ref().value
+    }
+}
+
+fn main() {}
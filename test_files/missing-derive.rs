@@ -0,0 +1,14 @@
+use rkyv_impl::archive_impl;
+
+pub struct Foo<T> {
+    field: T,
+}
+
+#[archive_impl]
+impl<T> Foo<T> {
+    pub fn get(&self) -> &T {
+        &self.field
+    }
+}
+
+fn main() {}
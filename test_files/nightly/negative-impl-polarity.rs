@@ -0,0 +1,18 @@
+#![feature(negative_impls)]
+
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo;
+
+pub trait Marker {}
+
+#[archive_impl]
+impl !Marker for Foo {}
+
+// Conflicts with the negative impl. If the archived impl were positive, this
+// would be a different error.
+impl Marker for ArchivedFoo {}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo {
+    reads: u32,
+}
+
+#[archive_impl(no_mut)]
+impl Foo {
+    pub fn read(&mut self) -> u32 {
+        self.reads += 1;
+        self.reads
+    }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+// Only the original type is `Clone`, so the bound on the archived copy is
+// trivially false, which is an error for a non-generic type.
+#[derive(Archive, Clone)]
+pub struct Foo {
+    value: u32,
+}
+
+#[archive_impl]
+impl Foo {
+    pub fn duplicate(&self) -> (Self, Self)
+    where
+        Self: Clone,
+    {
+        (self.clone(), self.clone())
+    }
+}
+
+fn main() {}
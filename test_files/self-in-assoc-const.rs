@@ -0,0 +1,19 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo;
+
+impl Foo {
+    pub const fn compute() -> usize {
+        4
+    }
+}
+
+// `compute` only exists on `Foo`, so the copied const can't find it.
+#[archive_impl]
+impl Foo {
+    pub const CAPACITY: usize = Self::compute();
+}
+
+fn main() {}
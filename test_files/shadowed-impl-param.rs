@@ -0,0 +1,17 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+// The compiler rejects the shadowed `T` on both impls.
+#[archive_impl(transform_bounds(T))]
+impl<T> Foo<T> {
+    pub fn contains<T>(&self, _value: &T) -> bool {
+        false
+    }
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+#![deny(deprecated)]
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+#[archive_impl]
+impl Foo {
+    pub fn empty() -> Self {
+        Self {
+            elements: Vec::new(),
+        }
+    }
+
+    pub fn try_new(elements: Vec<u32>) -> Result<Self, ()> {
+        Ok(Self { elements })
+    }
+
+    // Not warned about, since the method says what to do.
+    #[archive_method(skip)]
+    pub fn new() -> Self {
+        Self::empty()
+    }
+}
+
+#[derive(Archive)]
+pub struct Unit;
+
+// Not warned about, since trait impls copy every method.
+#[archive_impl]
+impl Default for Unit {
+    fn default() -> Self {
+        Self
+    }
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    field: T,
+}
+
+#[archive_impl]
+impl<T> Foo<T> {
+    #[archive_method(transform_bounds(S))]
+    pub fn get(&self) -> &T {
+        &self.field
+    }
+}
+
+fn main() {}
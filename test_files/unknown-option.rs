@@ -0,0 +1,10 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo;
+
+#[archive_impl(gen_try_from(serializer = "rkyv::Infallible"))]
+impl Foo {}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+#[archive_impl(transform_bounds(T), transform_methods(sum))]
+impl<T> Foo<T> {
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+}
+
+fn main() {}
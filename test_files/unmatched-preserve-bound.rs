@@ -0,0 +1,16 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+#[archive_impl(transform_bounds(T), preserve_bounds(T: Sync))]
+impl<T> Foo<T>
+where
+    T: Send,
+{
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    value: T,
+}
+
+#[archive_impl(transform_bounds(T), preserve_bounds('a: 'static))]
+impl<T> Foo<T> {
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+#[archive_impl(transform_bounds(T), assoc(U = "Frozen"))]
+impl<T> Foo<T> {}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo;
+
+#[archive_impl(skip)]
+impl Foo {}
+
+fn main() {}
@@ -0,0 +1,39 @@
+mod common;
+
+use rkyv::{Archive, Deserialize, Infallible};
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    values: Vec<u32>,
+}
+
+#[archive_impl]
+impl Foo {
+    // The archived method deserializes with any `D`, which only it declares.
+    #[archive_method(
+        add_generic(D: rkyv::Fallible + Default),
+        add_bounds(rkyv::vec::ArchivedVec<u32>: Deserialize<Vec<u32>, D>),
+        archived_body = {
+            self.values.deserialize(&mut D::default()).ok().unwrap()
+        },
+    )]
+    pub fn values(&self) -> Vec<u32> {
+        self.values.clone()
+    }
+
+    // Lifetimes are added before the method's other params.
+    #[archive_method(add_generic('b), archived_body = { self.values.first().copied() })]
+    pub fn first<T>(&self) -> Option<u32> {
+        self.values.first().copied()
+    }
+}
+
+#[test]
+fn archived_method_gains_generic() {
+    let foo = Foo { values: vec![1, 2] };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(archived.values::<Infallible>(), foo.values());
+    assert_eq!(archived.first::<()>(), foo.first::<()>());
+}
@@ -0,0 +1,34 @@
+#![deny(non_snake_case)]
+
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    value: u32,
+}
+
+// The archived body trips `non_snake_case`, which is only allowed on the
+// generated `impl`.
+#[archive_impl(allow_lints(non_snake_case, clippy::let_and_return))]
+impl Foo {
+    #[archive_method(archived_body = {
+        let Value = self.value;
+        Value
+    })]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[test]
+fn allow_lints_applies_to_archived_impl() {
+    let foo = Foo { value: 7 };
+    assert_eq!(foo.value(), 7);
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(archived.value(), 7);
+}
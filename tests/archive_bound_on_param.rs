@@ -0,0 +1,40 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T> {
+    value: T,
+}
+
+// `T: Archive<Archived = U>` already describes `T` itself, so it is kept as
+// written while `T: PartialEq` becomes `T::Archived: PartialEq`.
+#[archive_impl(transform_bounds(T))]
+impl<T, U> Foo<T>
+where
+    T: PartialEq + Archive<Archived = U>,
+    U: Copy,
+{
+    pub fn same(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+pub fn call_archived<T>(foo: &ArchivedFoo<T>) -> bool
+where
+    T: Archive,
+    T::Archived: PartialEq + Copy,
+{
+    foo.same(foo)
+}
+
+#[test]
+fn archive_bound_on_transformed_param() {
+    let foo = Foo { value: 5u32 };
+    assert!(foo.same(&foo));
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert!(call_archived(archived));
+}
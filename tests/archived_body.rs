@@ -0,0 +1,43 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    name: String,
+    counts: Vec<u32>,
+}
+
+#[archive_impl]
+impl Foo {
+    // `ArchivedString` is not `Clone`.
+    #[archive_method(archived_body = { self.name.as_str().to_owned() })]
+    pub fn owned_name(&self) -> String {
+        self.name.clone()
+    }
+
+    #[archive_method(archived_body = {
+        let mut total = 0;
+        for count in self.counts.iter() {
+            total += u64::from(*count);
+        }
+        total
+    })]
+    pub fn total(&self) -> u64 {
+        self.counts.iter().copied().map(u64::from).sum()
+    }
+}
+
+#[test]
+fn archived_body_replaces_original() {
+    let foo = Foo {
+        name: "foo".into(),
+        counts: vec![1, 2, 3],
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+
+    assert_eq!(foo.owned_name(), archived.owned_name());
+    assert_eq!(foo.total(), archived.total());
+}
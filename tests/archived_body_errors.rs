@@ -0,0 +1,50 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::str::Utf8Error;
+use std::string::FromUtf8Error;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    InvalidUtf8,
+}
+
+impl From<FromUtf8Error> for Error {
+    fn from(_: FromUtf8Error) -> Self {
+        Self::InvalidUtf8
+    }
+}
+
+impl From<Utf8Error> for Error {
+    fn from(_: Utf8Error) -> Self {
+        Self::InvalidUtf8
+    }
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    bytes: Vec<u8>,
+}
+
+#[archive_impl]
+impl Foo {
+    // `ArchivedVec` is not `Clone`, so the archived body borrows the bytes
+    // instead, and `?` converts a different error type.
+    #[archive_method(archived_body = {
+        Ok(std::str::from_utf8(&self.bytes)?.to_owned())
+    })]
+    pub fn to_text(&self) -> Result<String, Error> {
+        Ok(String::from_utf8(self.bytes.clone())?)
+    }
+}
+
+#[test]
+fn errors_match() {
+    for bytes in [b"foo".to_vec(), vec![0xff]] {
+        let foo = Foo { bytes };
+        let archived_bytes = common::archive(&foo);
+        let archived = archived_bytes.root();
+        assert_eq!(foo.to_text(), archived.to_text());
+    }
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub enum Shape {
+    Circle { radius: u32 },
+    Rect { width: u32, height: u32 },
+    Empty,
+}
+
+#[archive_impl]
+impl Shape {
+    // The original variants don't exist on `ArchivedShape`, so the archived
+    // copy matches over its own variants instead.
+    #[archive_method(archived_body = {
+        match self {
+            ArchivedShape::Circle { radius } => 3 * radius * radius,
+            ArchivedShape::Rect { width, height } => width * height,
+            ArchivedShape::Empty => 0,
+        }
+    })]
+    pub fn rough_area(&self) -> u32 {
+        match self {
+            Shape::Circle { radius } => 3 * radius * radius,
+            Shape::Rect { width, height } => width * height,
+            Shape::Empty => 0,
+        }
+    }
+
+    #[archive_method(archived_body = { matches!(self, ArchivedShape::Empty) })]
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Shape::Empty)
+    }
+
+    // `Self::` paths resolve to the variants of each type, so this body is
+    // shared as-is.
+    pub fn is_round(&self) -> bool {
+        matches!(self, Self::Circle { .. })
+    }
+}
+
+#[test]
+fn divergent_match_bodies() {
+    for shape in [
+        Shape::Circle { radius: 2 },
+        Shape::Rect {
+            width: 3,
+            height: 4,
+        },
+        Shape::Empty,
+    ] {
+        let bytes = common::archive(&shape);
+        let archived = bytes.root();
+        assert_eq!(shape.rough_area(), archived.rough_area());
+        assert_eq!(shape.is_empty(), archived.is_empty());
+        assert_eq!(shape.is_round(), archived.is_round());
+    }
+}
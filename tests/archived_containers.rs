@@ -0,0 +1,62 @@
+mod common;
+
+use rkyv::boxed::ArchivedBox;
+use rkyv::string::ArchivedString;
+use rkyv::vec::ArchivedVec;
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+    name: String,
+    boxed: Box<T>,
+}
+
+#[archive_impl(add_bounds(T: Archive))]
+impl<T> Foo<T> {
+    // On `ArchivedFoo`, this returns `&ArchivedVec<T::Archived>`.
+    #[archive_method(transform_return(T), archived_containers)]
+    pub fn elements(&self) -> &Vec<T> {
+        &self.elements
+    }
+
+    #[archive_method(archived_containers)]
+    pub fn name(&self) -> &std::string::String {
+        &self.name
+    }
+
+    #[archive_method(transform_return(T), archived_containers)]
+    #[allow(clippy::borrowed_box)]
+    pub fn boxed(&self) -> &Box<T> {
+        &self.boxed
+    }
+
+    // Argument types are also mapped with `transform_all`.
+    #[archive_method(transform_all(T), archived_containers)]
+    pub fn same_elements(&self, other: &Vec<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.elements == *other
+    }
+}
+
+#[test]
+fn std_containers_are_mapped() {
+    let foo = Foo {
+        elements: vec![1u32, 2],
+        name: "foo".to_string(),
+        boxed: Box::new(3u32),
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+
+    let elements: &ArchivedVec<u32> = archived.elements();
+    assert_eq!(elements.as_slice(), foo.elements().as_slice());
+    let name: &ArchivedString = archived.name();
+    assert_eq!(name.as_str(), foo.name());
+    let boxed: &ArchivedBox<u32> = archived.boxed();
+    assert_eq!(**boxed, **foo.boxed());
+    assert!(archived.same_elements(archived.elements()));
+}
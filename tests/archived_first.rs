@@ -0,0 +1,28 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+// Only the order of the emitted items changes, which is checked in
+// `test_files/expand/archived-first.rs`.
+#[archive_impl(archived_first)]
+impl Foo {
+    pub fn get_slice(&self) -> &[u32] {
+        &self.elements
+    }
+}
+
+pub fn call_archived(foo: &ArchivedFoo) -> &[u32] {
+    foo.get_slice()
+}
+
+#[test]
+fn original_still_generated() {
+    let foo = Foo {
+        elements: vec![1, 2],
+    };
+    assert_eq!(foo.get_slice(), &[1, 2]);
+}
@@ -0,0 +1,47 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+#[archive_impl(archived_methods_only)]
+impl Foo {
+    #[archive_method]
+    pub fn get_slice(&self) -> &[u32] {
+        &self.elements
+    }
+
+    pub fn push(&mut self, value: u32) {
+        self.elements.push(value);
+    }
+
+    pub fn into_elements(self) -> Vec<u32> {
+        self.elements
+    }
+}
+
+// The other methods are defined again here, which would conflict if they had
+// been copied.
+impl ArchivedFoo {
+    pub fn push(&mut self, _value: u32) {}
+
+    pub fn into_elements(self) -> Vec<u32> {
+        self.elements.to_vec()
+    }
+}
+
+pub fn call_archived(foo: &ArchivedFoo) -> &[u32] {
+    foo.get_slice()
+}
+
+#[test]
+fn original_has_all_methods() {
+    let mut foo = Foo {
+        elements: Vec::new(),
+    };
+    foo.push(1);
+    assert_eq!(foo.get_slice(), &[1]);
+    assert_eq!(foo.into_elements(), vec![1]);
+}
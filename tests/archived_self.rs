@@ -0,0 +1,52 @@
+mod common;
+
+use rkyv::{Archive, Archived, Fallible, Serialize};
+use rkyv_impl::*;
+use std::marker::PhantomData;
+
+pub struct Foo<T> {
+    value: u32,
+    _marker: PhantomData<T>,
+}
+
+// `T` only tags the original type, so the archived type has no params.
+#[repr(transparent)]
+pub struct ArchivedFoo {
+    value: Archived<u32>,
+}
+
+impl<T> Archive for Foo<T> {
+    type Archived = ArchivedFoo;
+    type Resolver = ();
+
+    unsafe fn resolve(&self, pos: usize, resolver: (), out: *mut ArchivedFoo) {
+        self.value.resolve(pos, resolver, out.cast());
+    }
+}
+
+impl<T, S: Fallible + ?Sized> Serialize<S> for Foo<T> {
+    fn serialize(&self, serializer: &mut S) -> Result<(), S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+// Generates `impl ArchivedFoo`, without `T` or its bound.
+#[archive_impl(archived_self = "ArchivedFoo")]
+impl<T: Default> Foo<T> {
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[test]
+fn archived_self_drops_param() {
+    let foo = Foo::<String> {
+        value: 9,
+        _marker: PhantomData,
+    };
+    assert_eq!(foo.value(), 9);
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(archived.value(), 9);
+}
@@ -0,0 +1,35 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo {
+    field: Vec<u32>,
+}
+
+pub trait GetSlice {
+    fn get_slice(&self) -> &[u32];
+}
+
+pub trait GetSliceArchived {
+    fn get_slice(&self) -> &[u32];
+}
+
+#[archive_impl(archived_trait = "GetSliceArchived")]
+impl GetSlice for Foo {
+    fn get_slice(&self) -> &[u32] {
+        &self.field
+    }
+}
+
+pub fn call_original(foo: &impl GetSlice) -> &[u32] {
+    foo.get_slice()
+}
+
+pub fn call_archived(foo: &impl GetSliceArchived) -> &[u32] {
+    foo.get_slice()
+}
+
+pub fn call_both(foo: &Foo, archived_foo: &ArchivedFoo) {
+    let _ = call_original(foo);
+    let _ = call_archived(archived_foo);
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use rkyv_impl::*;
+
+mod foo {
+    use super::*;
+    use rkyv::Archive;
+
+    #[derive(Archive, rkyv::Serialize)]
+    pub struct Foo {
+        pub value: u32,
+    }
+
+    #[archive_impl]
+    impl Foo {
+        // Private on `Foo`, but public on `ArchivedFoo`.
+        #[archive_method(vis = "pub")]
+        fn value(&self) -> u32 {
+            self.value
+        }
+
+        pub fn doubled(&self) -> u32 {
+            self.value() * 2
+        }
+    }
+}
+
+#[test]
+fn archived_method_visibility() {
+    let foo = foo::Foo { value: 4 };
+    assert_eq!(foo.doubled(), 8);
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(archived.value(), 4);
+    assert_eq!(archived.doubled(), 8);
+}
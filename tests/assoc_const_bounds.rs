@@ -0,0 +1,10 @@
+// Run with `RUSTFLAGS="--cfg nightly" cargo +nightly test`.
+//
+// The `type const` syntax is gated before `cfg` is evaluated, so the test lives
+// in a module file that is only loaded on nightly.
+#![cfg_attr(nightly, allow(incomplete_features))]
+#![cfg_attr(nightly, feature(min_generic_const_args))]
+
+#[cfg(nightly)]
+#[path = "nightly/assoc_const_bounds.rs"]
+mod nightly;
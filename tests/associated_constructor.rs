@@ -0,0 +1,82 @@
+// The warning for the skipped `empty` is checked in
+// `test_files/skipped-constructor-warning.rs`.
+#![allow(deprecated)]
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+#[archive_impl]
+impl Foo {
+    // Left out of the archived impl, since `ArchivedFoo` can't be constructed
+    // like this.
+    pub fn empty() -> Self {
+        Self {
+            elements: Vec::new(),
+        }
+    }
+
+    #[archive_method(skip)]
+    pub fn first_or_zero(&self) -> u32 {
+        self.elements.first().copied().unwrap_or(0)
+    }
+
+    // Doesn't construct anything, so it works on both types.
+    #[archive_method(keep)]
+    pub fn none() -> Option<Self> {
+        None
+    }
+
+    // References to `Self` are copied as usual.
+    pub fn pick<'a>(a: &'a Self, b: &'a Self) -> &'a Self {
+        if a.num_elements() >= b.num_elements() {
+            a
+        } else {
+            b
+        }
+    }
+
+    pub fn num_elements(&self) -> usize {
+        self.elements.len()
+    }
+}
+
+// The archived impl has its own `first_or_zero`, since the original was
+// skipped.
+impl ArchivedFoo {
+    pub fn first_or_zero(&self) -> u32 {
+        self.elements.first().copied().unwrap_or(0)
+    }
+}
+
+pub fn call_archived(foo: &ArchivedFoo) -> usize {
+    let _: u32 = foo.first_or_zero();
+    assert!(ArchivedFoo::none().is_none());
+    ArchivedFoo::pick(foo, foo).num_elements()
+}
+
+#[derive(Archive)]
+pub struct Count(u32);
+
+// Trait impls need every method, so `default` is copied.
+#[archive_impl]
+impl Default for Count {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+#[test]
+fn trait_constructor_is_copied() {
+    assert_eq!(Count::default().0, 1);
+    assert_eq!(ArchivedCount::default().0, 1);
+}
+
+#[test]
+fn constructor_on_original() {
+    assert_eq!(Foo::empty().num_elements(), 0);
+}
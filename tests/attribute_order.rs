@@ -0,0 +1,12 @@
+// Run with `RUSTFLAGS="--cfg nightly" cargo +nightly test`.
+//
+// Attribute `macro_rules!` need nightly, and their syntax is gated before `cfg`
+// is evaluated, so the test lives in a module file that is only loaded there.
+#![cfg_attr(nightly, feature(macro_attr))]
+
+#[cfg(nightly)]
+mod common;
+
+#[cfg(nightly)]
+#[path = "nightly/attribute_order.rs"]
+mod nightly;
@@ -0,0 +1,53 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+pub mod my {
+    // Wraps `rkyv::Archive` behind a crate-specific name.
+    pub trait MyArchive: rkyv::Archive<Archived = Self::Frozen> {
+        type Frozen;
+    }
+
+    impl<T: rkyv::Archive> MyArchive for T {
+        type Frozen = T::Archived;
+    }
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+// Generates `T: my::MyArchive` and `T::Frozen: PartialEq` instead of
+// `T: Archive` and `T::Archived: PartialEq`.
+#[archive_impl(
+    bound_trait = "my::MyArchive",
+    transform_bounds(T),
+    assoc(T = "Frozen")
+)]
+impl<T: PartialEq> Foo<T> {
+    pub fn has_adjacent_duplicates(&self) -> bool {
+        self.elements.windows(2).any(|pair| pair[0] == pair[1])
+    }
+}
+
+pub fn call_archived<T>(foo: &ArchivedFoo<T>) -> bool
+where
+    T: my::MyArchive,
+    T::Frozen: PartialEq,
+{
+    foo.has_adjacent_duplicates()
+}
+
+#[test]
+fn custom_bound_trait() {
+    let foo = Foo {
+        elements: vec![1u32, 2, 2],
+    };
+    assert!(foo.has_adjacent_duplicates());
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert!(call_archived(archived));
+}
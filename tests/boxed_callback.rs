@@ -0,0 +1,46 @@
+mod common;
+
+use rkyv::string::ArchivedString;
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+#[archive_impl(add_bounds(T: Archive))]
+impl<T> Foo<T> {
+    // On `ArchivedFoo`, the callback is a
+    // `Box<dyn Fn(&T::Archived) + Send + Sync + 'a>`.
+    #[archive_method(transform_all(T))]
+    #[allow(clippy::type_complexity)]
+    pub fn with_callback<'a>(&self, cb: Box<dyn Fn(&T) + Send + Sync + 'a>) {
+        for element in self.elements.iter() {
+            cb(element);
+        }
+    }
+}
+
+fn assert_send_sync<T: Send + Sync + ?Sized>(_: &T) {}
+
+#[test]
+fn archived_callback_takes_archived_elements() {
+    let foo = Foo {
+        elements: vec!["a".to_string(), "bc".to_string()],
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+
+    let len = AtomicUsize::new(0);
+    let cb: Box<dyn Fn(&ArchivedString) + Send + Sync + '_> = Box::new(|x| {
+        len.fetch_add(x.len(), Ordering::Relaxed);
+    });
+    assert_send_sync(&*cb);
+    archived.with_callback(cb);
+    foo.with_callback(Box::new(|x: &String| {
+        len.fetch_add(x.len(), Ordering::Relaxed);
+    }));
+    assert_eq!(len.load(Ordering::Relaxed), 6);
+}
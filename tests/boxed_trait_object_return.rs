@@ -0,0 +1,31 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+#[archive(bound(archive = "T: Archive"))]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+#[archive_impl(add_bounds(T: Archive))]
+impl<T> Foo<T> {
+    // Only `T` inside the trait object is rewritten; the `+ 'a` bound is kept.
+    #[archive_method(transform_return(T))]
+    pub fn boxed<'a>(&'a self) -> Box<dyn Iterator<Item = &'a T> + 'a> {
+        Box::new(self.elements.iter())
+    }
+}
+
+#[test]
+fn boxed_trait_object_return() {
+    let foo = Foo {
+        elements: vec![1u32, 2, 3],
+    };
+    assert_eq!(foo.boxed().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(archived.boxed().copied().collect::<Vec<_>>(), [1, 2, 3]);
+}
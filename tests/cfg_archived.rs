@@ -0,0 +1,42 @@
+// `archived_extras` is not a feature of this crate, so it's never enabled.
+#![allow(unexpected_cfgs)]
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+#[archive_impl]
+impl Foo {
+    #[archive_method(cfg_archived = "archived_extras")]
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
+
+// Would conflict if the archived `len` had been compiled.
+#[cfg(not(feature = "archived_extras"))]
+impl ArchivedFoo {
+    pub fn len(&self) -> usize {
+        usize::MAX
+    }
+}
+
+pub fn call_archived(foo: &ArchivedFoo) -> usize {
+    foo.len()
+}
+
+#[test]
+fn original_is_not_gated() {
+    let foo = Foo {
+        elements: vec![1, 2],
+    };
+    assert_eq!(foo.len(), 2);
+}
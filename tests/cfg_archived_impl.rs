@@ -0,0 +1,27 @@
+// `rkyv` is not a feature of this crate, so it's never enabled.
+#![allow(unexpected_cfgs)]
+
+use rkyv_impl::*;
+
+// The common pattern of an optional `rkyv` dependency.
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive))]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+// Without the feature, there's no `ArchivedFoo`, so the archived `impl` and
+// the generated `Ord` impls must be left out.
+#[archive_impl(cfg_archived = "rkyv", gen_ord = "key")]
+impl Foo {
+    pub fn key(&self) -> usize {
+        self.elements.len()
+    }
+}
+
+#[test]
+fn original_is_not_gated() {
+    let foo = Foo {
+        elements: vec![1, 2],
+    };
+    assert_eq!(foo.key(), 2);
+}
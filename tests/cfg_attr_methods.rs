@@ -0,0 +1,46 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+// The `cfg_attr`s are copied as written, so they are evaluated the same way on
+// both impls.
+#[archive_impl]
+impl Foo {
+    #[cfg_attr(test, inline)]
+    #[cfg_attr(not(test), inline(never))]
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    #[cfg_attr(all(), must_use)]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    #[archive_method(skip)]
+    pub fn push(&mut self, element: u32) {
+        self.elements.push(element);
+    }
+
+    pub fn first(&self) -> Option<u32> {
+        self.elements.first().copied()
+    }
+}
+
+#[test]
+fn cfg_attr_methods() {
+    let mut foo = Foo { elements: vec![] };
+    foo.push(3);
+    assert_eq!((foo.len(), foo.first()), (1, Some(3)));
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert!(!archived.is_empty());
+    assert_eq!((archived.len(), archived.first()), (1, Some(3)));
+}
@@ -0,0 +1,65 @@
+// Integration tests are always built with `cfg(test)`, so `test` and
+// `not(test)` stand in for a feature that is on or off.
+
+pub mod enabled {
+    use rkyv::Archive;
+    use rkyv_impl::archive_impl;
+
+    #[cfg_attr(test, derive(Archive))]
+    pub struct Foo {
+        field: Vec<u32>,
+    }
+
+    #[cfg(test)]
+    #[archive_impl]
+    impl Foo {
+        pub fn get_slice(&self) -> &[u32] {
+            &self.field
+        }
+    }
+
+    // Attributes after `archive_impl` are copied to both impls.
+    #[archive_impl]
+    #[cfg(test)]
+    impl Foo {
+        pub fn get_first(&self) -> Option<&u32> {
+            self.field.first()
+        }
+    }
+
+    pub fn call_archived(foo: ArchivedFoo) {
+        let _: &[u32] = foo.get_slice();
+        let _: Option<&u32> = foo.get_first();
+    }
+}
+
+pub mod disabled {
+    // Without the derive, there is no `ArchivedFoo`, so the impl must be gated
+    // by the same condition.
+    #[cfg_attr(not(test), derive(rkyv::Archive))]
+    pub struct Foo {
+        pub field: Vec<u32>,
+    }
+
+    #[cfg(not(test))]
+    #[rkyv_impl::archive_impl]
+    impl Foo {
+        pub fn get_slice(&self) -> &[u32] {
+            &self.field
+        }
+    }
+
+    #[rkyv_impl::archive_impl]
+    #[cfg(not(test))]
+    impl Foo {
+        pub fn get_first(&self) -> Option<&u32> {
+            self.field.first()
+        }
+    }
+}
+
+#[test]
+fn disabled_impl_is_absent() {
+    let foo = disabled::Foo { field: vec![1] };
+    assert_eq!(foo.field.len(), 1);
+}
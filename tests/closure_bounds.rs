@@ -0,0 +1,34 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+#[archive_impl(transform_bounds(T))]
+impl<T> Foo<T> {
+    // The archived method gets `F: FnMut(&T::Archived)`.
+    #[archive_method(transform_bounds(T))]
+    pub fn for_each_element<F: FnMut(&T)>(&self, mut f: F) {
+        for element in self.elements.iter() {
+            f(element);
+        }
+    }
+}
+
+#[test]
+fn archived_closure_takes_archived_elements() {
+    let foo = Foo {
+        elements: vec![1u32, 2, 3],
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+
+    let mut sum = 0;
+    foo.for_each_element(|x: &u32| sum += x);
+    archived.for_each_element(|x: &<u32 as Archive>::Archived| sum += x);
+    assert_eq!(sum, 12);
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    value: u32,
+}
+
+#[archive_impl]
+impl Foo {
+    // Copied as written to the archived method.
+    #[cold]
+    #[inline(never)]
+    pub fn unlikely(&self) -> u32 {
+        self.value
+    }
+
+    // Copying this would export the same symbol twice, so it's skipped.
+    #[archive_method(skip)]
+    #[no_mangle]
+    pub extern "C" fn rkyv_impl_codegen_attributes_value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[test]
+fn cold_is_preserved() {
+    let foo = Foo { value: 3 };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(foo.unlikely(), archived.unlikely());
+    assert_eq!(foo.rkyv_impl_codegen_attributes_value(), 3);
+}
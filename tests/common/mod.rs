@@ -0,0 +1,24 @@
+// Serialization boilerplate shared by the tests that call archived methods.
+
+use rkyv::{ser::serializers::AllocSerializer, AlignedVec, Archive, Serialize};
+use std::marker::PhantomData;
+
+/// The bytes of an archived `T`, which can only be made by [`archive`].
+pub struct ArchivedBytes<T> {
+    bytes: AlignedVec,
+    marker: PhantomData<T>,
+}
+
+impl<T: Archive> ArchivedBytes<T> {
+    pub fn root(&self) -> &T::Archived {
+        // SAFETY: The bytes were serialized from a `T` by `archive`.
+        unsafe { rkyv::archived_root::<T>(&self.bytes) }
+    }
+}
+
+pub fn archive<T: Serialize<AllocSerializer<256>>>(value: &T) -> ArchivedBytes<T> {
+    ArchivedBytes {
+        bytes: rkyv::to_bytes::<_, 256>(value).unwrap(),
+        marker: PhantomData,
+    }
+}
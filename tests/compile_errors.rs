@@ -3,4 +3,29 @@ fn compile_errors() {
     let t = trybuild::TestCases::new();
     t.compile_fail("test_files/unexpected-input.rs");
     t.compile_fail("test_files/unsupported-self-type.rs");
+    t.compile_fail("test_files/archived-trait-inherent-impl.rs");
+    t.compile_fail("test_files/undeclared-transform-param.rs");
+    t.compile_fail("test_files/missing-derive.rs");
+    t.compile_fail("test_files/blanket-impl.rs");
+    t.compile_fail("test_files/self-in-assoc-const.rs");
+    t.compile_fail("test_files/unmatched-preserve-bound.rs");
+    t.compile_fail("test_files/archived-missing-method.rs");
+    t.compile_fail("test_files/shadowed-impl-param.rs");
+    t.compile_fail("test_files/unknown-transform-method.rs");
+    t.compile_fail("test_files/wrong-scope.rs");
+    t.compile_fail("test_files/unsupported-meta.rs");
+    t.compile_fail("test_files/unknown-option.rs");
+    t.compile_fail("test_files/archive-method-on-const.rs");
+    t.compile_fail("test_files/impl-param-defaults.rs");
+    t.compile_fail("test_files/untransformed-assoc-param.rs");
+    t.compile_fail("test_files/archive-method-on-macro.rs");
+    t.compile_fail("test_files/allow-lints-original-impl.rs");
+    t.compile_fail("test_files/self-bound-not-satisfied.rs");
+    t.compile_fail("test_files/archived-private-method.rs");
+    t.compile_fail("test_files/archived-self-removed-param.rs");
+    t.compile_fail("test_files/no-mut-body-needs-mut.rs");
+    t.compile_fail("test_files/skipped-constructor-warning.rs");
+    t.compile_fail("test_files/gen-roundtrip-test-missing-options.rs");
+    t.compile_fail("test_files/unsupported-preserve-bound.rs");
+    t.compile_fail("test_files/keep-self-wrapper-without-args.rs");
 }
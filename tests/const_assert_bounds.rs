@@ -0,0 +1,71 @@
+// The nightly module runs with `RUSTFLAGS="--cfg nightly" cargo +nightly test`.
+#![cfg_attr(nightly, allow(incomplete_features))]
+#![cfg_attr(nightly, feature(generic_const_exprs))]
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+pub struct Assert<const N: usize>;
+
+pub trait IsTrue {}
+
+impl IsTrue for Assert<1> {}
+
+#[derive(Archive)]
+pub struct Foo<T, const N: usize> {
+    elements: [T; N],
+}
+
+// The const argument block is an expression, so it is left alone while `T` is
+// transformed.
+#[archive_impl(transform_bounds(T), add_bounds(T: Archive))]
+impl<T, const N: usize> Foo<T, N>
+where
+    T: PartialEq,
+    Assert<{ N }>: IsTrue,
+{
+    pub fn first_is_last(&self) -> bool {
+        self.elements[0] == self.elements[N - 1]
+    }
+}
+
+pub fn call_archived<T>(foo: &ArchivedFoo<T, 1>) -> bool
+where
+    T: Archive,
+    T::Archived: PartialEq,
+{
+    foo.first_is_last()
+}
+
+#[cfg(nightly)]
+pub mod nightly {
+    use super::*;
+
+    pub struct Check<const B: bool>;
+
+    impl IsTrue for Check<true> {}
+
+    #[derive(Archive)]
+    pub struct Bar<T, const N: usize> {
+        elements: [T; N],
+    }
+
+    #[archive_impl(transform_bounds(T), add_bounds(T: Archive))]
+    impl<T, const N: usize> Bar<T, N>
+    where
+        T: PartialEq,
+        Check<{ N > 0 }>: IsTrue,
+    {
+        pub fn first_is_last(&self) -> bool {
+            self.elements[0] == self.elements[N - 1]
+        }
+    }
+
+    pub fn call_archived<T>(bar: &ArchivedBar<T, 2>) -> bool
+    where
+        T: Archive,
+        T::Archived: PartialEq,
+    {
+        bar.first_is_last()
+    }
+}
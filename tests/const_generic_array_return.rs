@@ -0,0 +1,35 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T, const N: usize> {
+    elements: [T; N],
+}
+
+#[archive_impl(add_bounds(T: Archive))]
+impl<T, const N: usize> Foo<T, N> {
+    // `N` is kept on the archived `impl`, and only the element type becomes
+    // `T::Archived`.
+    #[archive_method(transform_bounds(T), transform_return(T))]
+    pub fn to_array(&self) -> [T; N]
+    where
+        T: Clone,
+    {
+        self.elements.clone()
+    }
+}
+
+#[test]
+fn const_generic_array_return() {
+    let foo = Foo {
+        elements: [1u32, 2, 3],
+    };
+    assert_eq!(foo.to_array(), [1, 2, 3]);
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    let array: [rkyv::Archived<u32>; 3] = archived.to_array();
+    assert_eq!(array, [1, 2, 3]);
+}
@@ -0,0 +1,20 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo<const N: usize> {
+    data: [u32; N],
+}
+
+// Only the last segment's ident is renamed, so the const expression argument is
+// carried over as `ArchivedFoo<{ 1 + 1 }>`.
+#[archive_impl]
+impl Foo<{ 1 + 1 }> {
+    pub fn pair(&self) -> (u32, u32) {
+        (self.data[0], self.data[1])
+    }
+}
+
+pub fn call_archived(foo: &ArchivedFoo<2>) -> (u32, u32) {
+    foo.pair()
+}
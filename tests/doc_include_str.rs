@@ -0,0 +1,25 @@
+//! Non-literal doc attributes are copied onto the archived methods as tokens.
+//! `missing_docs` fails if either copy loses its docs.
+#![deny(missing_docs)]
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+/// A list of elements.
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+#[archive_impl]
+impl Foo {
+    #[doc = include_str!("doc-snippet.md")]
+    pub fn get_slice(&self) -> &[u32] {
+        &self.elements
+    }
+}
+
+/// Calls the archived method.
+pub fn call_archived(foo: &ArchivedFoo) -> &[u32] {
+    foo.get_slice()
+}
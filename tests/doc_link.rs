@@ -0,0 +1,26 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+// The archived methods link to `Foo::len` and `Foo::is_empty`, without the
+// generic arguments. The doc attributes are checked in
+// `test_files/expand/doc-link.rs`.
+#[archive_impl(doc_link, add_bounds(T: Archive))]
+impl<T> Foo<T> {
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
+
+pub fn call_archived<T: Archive>(foo: &ArchivedFoo<T>) -> (usize, bool) {
+    (foo.len(), foo.is_empty())
+}
@@ -0,0 +1,33 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+pub trait Describe {
+    fn describe(&self) -> String;
+}
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+// Only `T` is rewritten; the trait object is left as `dyn Describe`.
+#[archive_impl(transform_bounds(T), add_bounds(T: Archive))]
+impl<T> Foo<T>
+where
+    T: AsRef<dyn Describe>,
+{
+    pub fn describe_all(&self) -> Vec<String> {
+        self.elements
+            .iter()
+            .map(|e| e.as_ref().describe())
+            .collect()
+    }
+}
+
+pub fn call_archived<T>(foo: &ArchivedFoo<T>) -> Vec<String>
+where
+    T: Archive,
+    T::Archived: AsRef<dyn Describe>,
+{
+    foo.describe_all()
+}
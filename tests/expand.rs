@@ -0,0 +1,97 @@
+// Snapshot tests of the expansion, printed with `RKYV_IMPL_DEBUG=1`.
+//
+// Each `test_files/expand/*.rs` is built as its own crate, and the output is
+// compared against the `.expanded` file next to it. Run with
+// `RKYV_IMPL_EXPAND=overwrite` to update the snapshots.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn expand() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut fixtures: Vec<_> = fs::read_dir(manifest_dir.join("test_files/expand"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    fixtures.sort();
+    assert!(!fixtures.is_empty());
+
+    let mut failures = Vec::new();
+    for fixture in fixtures {
+        let expanded = expand_fixture(manifest_dir, &fixture);
+        let snapshot = fixture.with_extension("expanded");
+        if std::env::var("RKYV_IMPL_EXPAND").as_deref() == Ok("overwrite") {
+            fs::write(&snapshot, &expanded).unwrap();
+        } else if fs::read_to_string(&snapshot).ok().as_deref() != Some(&expanded) {
+            failures.push(format!(
+                "{} doesn't match {}:\n{expanded}",
+                fixture.display(),
+                snapshot.display()
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+fn expand_fixture(manifest_dir: &Path, fixture: &Path) -> String {
+    let project_dir = manifest_dir.join("target/tests/expand");
+    fs::create_dir_all(project_dir.join("src")).unwrap();
+    fs::write(
+        project_dir.join("Cargo.toml"),
+        format!(
+            r#"[package]
+name = "rkyv-impl-expand"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[lib]
+path = "src/lib.rs"
+
+[dependencies]
+rkyv = "0.7"
+rkyv_impl = {{ path = {:?} }}
+
+# Stands in for an optional `rkyv` dependency, for `cfg_archived`.
+[features]
+default = ["rkyv"]
+rkyv = []
+
+[workspace]
+"#,
+            manifest_dir
+        ),
+    )
+    .unwrap();
+    // Build with the same dependency versions as the tests, if they're locked.
+    let lockfile = manifest_dir.join("Cargo.lock");
+    if lockfile.exists() {
+        fs::copy(lockfile, project_dir.join("Cargo.lock")).unwrap();
+    }
+    fs::copy(fixture, project_dir.join("src/lib.rs")).unwrap();
+
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".into());
+    // The macro output is only printed when the crate is rebuilt.
+    let status = Command::new(&cargo)
+        .args(["clean", "--quiet", "--package", "rkyv-impl-expand"])
+        .current_dir(&project_dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let output = Command::new(&cargo)
+        .args(["check", "--quiet"])
+        .env("RKYV_IMPL_DEBUG", "1")
+        .current_dir(&project_dir)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        output.status.success(),
+        "failed to build {}:\n{stderr}",
+        fixture.display()
+    );
+    stderr
+}
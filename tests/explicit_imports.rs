@@ -0,0 +1,25 @@
+// The crate only exports the two attribute macros, so they can be named
+// directly instead of glob-imported.
+use rkyv::Archive;
+use rkyv_impl::{archive_impl, archive_method};
+
+#[derive(Archive)]
+pub struct Foo {
+    value: u32,
+}
+
+#[archive_impl]
+impl Foo {
+    #[archive_method(skip)]
+    pub fn set(&mut self, value: u32) {
+        self.value = value;
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+pub fn call_archived(foo: &ArchivedFoo) -> bool {
+    foo.is_zero()
+}
@@ -0,0 +1,35 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::marker::PhantomData;
+
+#[derive(Archive)]
+pub struct Foo<T, M> {
+    elements: Vec<T>,
+    marker: PhantomData<M>,
+}
+
+// Not `Archive`.
+#[derive(Default)]
+pub struct NotArchivable(pub u8);
+
+// Only the params listed in `transform_bounds` are constrained, so
+// `NotArchivable` never needs to implement `Archive`.
+#[archive_impl(transform_bounds(T))]
+impl<T: Clone> Foo<T, NotArchivable> {
+    #[archive_method(transform_bounds(T), transform_return(T))]
+    pub fn first_with_marker(&self) -> Option<(T, NotArchivable)>
+    where
+        NotArchivable: Default,
+    {
+        let first = self.elements.first().cloned()?;
+        Some((first, NotArchivable::default()))
+    }
+}
+
+pub fn call_archived<T>(foo: &ArchivedFoo<T, NotArchivable>) -> bool
+where
+    T: Archive,
+    T::Archived: Clone,
+{
+    foo.first_with_marker().is_some()
+}
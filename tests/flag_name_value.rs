@@ -0,0 +1,35 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+#[archive_impl(skip_mut_methods = true, archived_methods_only = false)]
+impl Foo {
+    pub fn push(&mut self, value: u32) {
+        self.elements.push(value);
+    }
+
+    pub fn get_slice(&self) -> &[u32] {
+        &self.elements
+    }
+
+    // `keep = false` is the same as leaving it out.
+    #[archive_method(keep = false)]
+    pub fn clear(&mut self) {
+        self.elements.clear();
+    }
+}
+
+// Would conflict if `push` or `clear` had been copied.
+impl ArchivedFoo {
+    pub fn push(&mut self, _value: u32) {}
+
+    pub fn clear(&mut self) {}
+}
+
+pub fn call_archived(foo: &ArchivedFoo) -> &[u32] {
+    foo.get_slice()
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::collections::{BTreeSet, HashSet};
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    id: u32,
+    name: String,
+}
+
+#[archive_impl(gen_ord = "key", gen_hash = "key")]
+impl Foo {
+    pub fn key(&self) -> (u32, &str) {
+        (self.id, &self.name)
+    }
+}
+
+fn foo(id: u32, name: &str) -> Foo {
+    Foo {
+        id,
+        name: name.into(),
+    }
+}
+
+#[test]
+fn archived_values_in_sets() {
+    let foos = vec![foo(2, "b"), foo(1, "a"), foo(2, "b"), foo(1, "c")];
+    let bytes = common::archive(&foos);
+    let archived = bytes.root();
+
+    let ordered: BTreeSet<&ArchivedFoo> = archived.iter().collect();
+    let keys: Vec<_> = ordered.iter().map(|foo| foo.key()).collect();
+    assert_eq!(keys, [(1, "a"), (1, "c"), (2, "b")]);
+
+    let hashed: HashSet<&ArchivedFoo> = archived.iter().collect();
+    assert_eq!(hashed.len(), 3);
+    assert!(hashed.contains(&archived[1]));
+}
@@ -0,0 +1,37 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+fn sample_foo() -> Foo {
+    Foo {
+        elements: vec![1, 2, 3],
+    }
+}
+
+// Generates a `roundtrip` test comparing `sum` on `Foo` and `ArchivedFoo`.
+#[archive_impl(gen_roundtrip_test(sample = "sample_foo", method = "sum"))]
+impl Foo {
+    pub fn sum(&self) -> u32 {
+        self.elements.iter().sum()
+    }
+
+    pub fn get_slice(&self) -> &[u32] {
+        &self.elements
+    }
+}
+
+// A second test for the same type gets its own module.
+#[archive_impl(gen_roundtrip_test(sample = "sample_foo", method = "len"))]
+impl Foo {
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
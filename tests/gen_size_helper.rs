@@ -0,0 +1,37 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+    count: u64,
+}
+
+#[archive_impl(gen_size_helper)]
+impl Foo {
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+#[derive(Archive)]
+pub struct Bar<T> {
+    value: T,
+}
+
+#[archive_impl(gen_size_helper, add_bounds(T: Archive))]
+impl<T> Bar<T> {}
+
+#[test]
+fn matches_size_of() {
+    assert_eq!(
+        ArchivedFoo::archived_size(),
+        std::mem::size_of::<ArchivedFoo>()
+    );
+    assert_eq!(
+        ArchivedBar::<u16>::archived_size(),
+        std::mem::size_of::<ArchivedBar<u16>>()
+    );
+}
+
+const _: () = assert!(ArchivedFoo::archived_size() > 0);
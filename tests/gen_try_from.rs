@@ -0,0 +1,47 @@
+mod common;
+
+use rkyv::{de::deserializers::SharedDeserializeMap, Archive, Deserialize, Serialize};
+use rkyv_impl::*;
+use std::rc::Rc;
+
+#[derive(Archive, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+#[archive_impl(transform_bounds(T), gen_try_from)]
+impl<T> Foo<T> {
+    pub fn num_elements(&self) -> usize {
+        self.elements.len()
+    }
+}
+
+#[derive(Archive, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Shared {
+    value: Rc<u32>,
+}
+
+// `Rc` needs a deserializer that supports shared pointers.
+#[archive_impl(gen_try_from(deserializer = "SharedDeserializeMap"))]
+impl Shared {}
+
+#[test]
+fn round_trip_with_try_from() {
+    let foo = Foo {
+        elements: vec![1u32, 2, 3],
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+
+    assert_eq!(archived.num_elements(), 3);
+    assert_eq!(Foo::try_from(archived), Ok(foo));
+}
+
+#[test]
+fn round_trip_with_configured_deserializer() {
+    let shared = Shared { value: Rc::new(1) };
+    let bytes = common::archive(&shared);
+    let archived = bytes.root();
+
+    assert_eq!(Shared::try_from(archived).unwrap(), shared);
+}
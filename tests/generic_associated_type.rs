@@ -0,0 +1,35 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+pub trait Elements {
+    type Elem;
+    type Iter<'a>: Iterator<Item = &'a Self::Elem>
+    where
+        Self: 'a;
+
+    fn elements(&self) -> Self::Iter<'_>;
+}
+
+// The archived impl gets `type Elem = T::Archived` and
+// `type Iter<'a> = std::slice::Iter<'a, T::Archived> where Self: 'a`.
+#[archive_impl(transform_bounds(T))]
+impl<T> Elements for Foo<T> {
+    type Elem = T;
+    type Iter<'a>
+        = std::slice::Iter<'a, T>
+    where
+        Self: 'a;
+
+    fn elements(&self) -> Self::Iter<'_> {
+        self.elements.iter()
+    }
+}
+
+pub fn call_archived<T: Archive>(foo: &ArchivedFoo<T>) -> Option<&T::Archived> {
+    foo.elements().next()
+}
@@ -0,0 +1,29 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+// A `$t:ty` fragment reaches `archive_impl` as an invisible group.
+macro_rules! impl_len {
+    ($t:ty) => {
+        #[archive_impl]
+        impl $t {
+            pub fn len(&self) -> usize {
+                self.elements.len()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.elements.is_empty()
+            }
+        }
+    };
+}
+
+impl_len!(Foo);
+
+pub fn call_archived(foo: &ArchivedFoo) -> usize {
+    foo.len()
+}
@@ -0,0 +1,40 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+// Method generics can't shadow impl generics, so each `transform_bounds` only
+// applies to its own `where` clause.
+#[archive_impl(transform_bounds(T))]
+impl<T> Foo<T>
+where
+    T: PartialEq,
+{
+    // Not transformed, so this stays `T: Clone`.
+    pub fn first_is_clone(&self) -> bool
+    where
+        T: Clone,
+    {
+        !self.elements.is_empty()
+    }
+
+    // Transformed to `T::Archived: Default`.
+    #[archive_method(transform_bounds(T))]
+    pub fn first_is_default(&self) -> bool
+    where
+        T: Default,
+    {
+        !self.elements.is_empty()
+    }
+}
+
+pub fn call_archived<T>(foo: &ArchivedFoo<T>) -> bool
+where
+    T: Archive + Clone,
+    T::Archived: PartialEq + Default,
+{
+    foo.first_is_clone() && foo.first_is_default()
+}
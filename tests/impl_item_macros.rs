@@ -0,0 +1,30 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+macro_rules! len_method {
+    () => {
+        pub fn len(&self) -> usize {
+            self.elements.len()
+        }
+    };
+}
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+// Macro invocations are copied verbatim and expand separately in each impl.
+// The methods they generate can't be transformed or skipped.
+#[archive_impl(add_bounds(T: Archive))]
+impl<T> Foo<T> {
+    len_method!();
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+pub fn call_archived<T: Archive>(foo: &ArchivedFoo<T>) -> (usize, bool) {
+    (foo.len(), foo.is_empty())
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+// Both impls are copied with the same arguments.
+#[archive_impl(skip_mut_methods)]
+mod foo_impls {
+    use super::{ArchivedFoo, Foo};
+
+    impl Foo {
+        pub fn len(&self) -> usize {
+            self.elements.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.elements.is_empty()
+        }
+
+        pub fn push(&mut self, element: u32) {
+            self.elements.push(element);
+        }
+    }
+
+    impl Foo {
+        pub fn first(&self) -> Option<u32> {
+            self.elements.first().copied()
+        }
+    }
+
+    pub fn call_archived(foo: &ArchivedFoo) -> (usize, Option<u32>) {
+        (foo.len(), foo.first())
+    }
+}
+
+#[test]
+fn module_impls_are_archived() {
+    let mut foo = Foo { elements: vec![] };
+    foo.push(5);
+    foo.push(6);
+    assert_eq!((foo.len(), foo.first()), (2, Some(5)));
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert!(!archived.is_empty());
+    assert_eq!(foo_impls::call_archived(archived), (2, Some(5)));
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize, rkyv::Deserialize, Debug, PartialEq)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+fn sample_foo() -> Foo {
+    Foo {
+        elements: vec![2, 1],
+    }
+}
+
+// The generated items are only emitted once for `ArchivedFoo`, even though
+// there are two impls of it.
+#[archive_impl(
+    gen_ord = "key",
+    gen_hash = "key",
+    gen_try_from,
+    gen_roundtrip_test(sample = "sample_foo", method = "key")
+)]
+mod foo_impls {
+    use super::{sample_foo, ArchivedFoo, Foo};
+
+    impl Foo {
+        pub fn key(&self) -> u32 {
+            self.elements.iter().sum()
+        }
+    }
+
+    impl Foo {
+        pub fn len(&self) -> usize {
+            self.elements.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.elements.is_empty()
+        }
+    }
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Bar<T> {
+    elements: Vec<T>,
+}
+
+// `Bar<T>` and `super::Bar<u32>` are written differently, but both get
+// `ArchivedBar`, so the generated items are still only emitted once.
+#[archive_impl(gen_ord = "len", gen_hash = "len")]
+mod bar_impls {
+    use super::{ArchivedBar, Bar};
+    use rkyv::Archive;
+
+    impl<T: Archive> Bar<T> {
+        pub fn len(&self) -> usize {
+            self.elements.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.elements.is_empty()
+        }
+    }
+
+    impl super::Bar<u32> {
+        pub fn first(&self) -> Option<&u32> {
+            self.elements.first()
+        }
+    }
+}
+
+#[test]
+fn generated_items_are_emitted_once() {
+    let foo = sample_foo();
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(archived.len(), 2);
+    assert!(archived == archived);
+    assert_eq!(Foo::try_from(archived), Ok(foo));
+}
+
+#[test]
+fn generated_items_are_emitted_once_for_differently_written_types() {
+    let bar = Bar {
+        elements: vec![3u32, 4],
+    };
+    let bytes = common::archive(&bar);
+    let archived = bytes.root();
+    assert_eq!(archived.first(), Some(&3));
+    assert!(archived == archived);
+}
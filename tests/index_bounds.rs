@@ -0,0 +1,48 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::marker::PhantomData;
+use std::ops::Index;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T, U> {
+    container: T,
+    marker: PhantomData<U>,
+}
+
+// Becomes `T::Archived: Index<usize, Output = U::Archived>`, with `usize` left
+// alone.
+#[archive_impl(transform_bounds(T, U))]
+impl<T, U> Foo<T, U>
+where
+    T: Index<usize, Output = U>,
+{
+    #[archive_method(transform_return(U))]
+    pub fn get(&self, index: usize) -> &U {
+        &self.container[index]
+    }
+}
+
+pub fn call_archived<T, U>(foo: &ArchivedFoo<T, U>) -> &U::Archived
+where
+    T: Archive,
+    U: Archive,
+    T::Archived: Index<usize, Output = U::Archived>,
+{
+    foo.get(0)
+}
+
+#[test]
+fn index_bound_over_two_params() {
+    let foo = Foo::<Vec<u32>, u32> {
+        container: vec![4, 5],
+        marker: PhantomData,
+    };
+    assert_eq!(*foo.get(1), 5);
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(*call_archived(archived), 4);
+    assert_eq!(*archived.get(1), 5);
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::borrow::Cow;
+
+pub trait Total {
+    fn total(&self) -> u32;
+}
+
+#[derive(Archive, rkyv::Serialize, Clone)]
+#[archive_attr(derive(Clone))]
+pub struct Foo {
+    x: u32,
+    y: u32,
+}
+
+// Only `Foo` is renamed, so this generates
+// `impl Total for Cow<'_, ArchivedFoo>`.
+#[archive_impl(keep_self_wrapper, transform_self_args(Foo))]
+impl Total for Cow<'_, Foo> {
+    fn total(&self) -> u32 {
+        self.x + self.y
+    }
+}
+
+#[archive_impl(keep_self_wrapper, transform_self_args(Foo))]
+impl Total for [Foo] {
+    fn total(&self) -> u32 {
+        self.iter().map(|foo| foo.x + foo.y).sum()
+    }
+}
+
+#[test]
+fn cow_self_type() {
+    let foo = Foo { x: 1, y: 2 };
+    assert_eq!(Cow::Borrowed(&foo).total(), 3);
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(Cow::Borrowed(archived).total(), 3);
+}
+
+#[test]
+fn slice_self_type() {
+    let foos = vec![Foo { x: 1, y: 2 }, Foo { x: 3, y: 4 }];
+    assert_eq!(foos[..].total(), 10);
+
+    let bytes = common::archive(&foos);
+    let archived = bytes.root();
+    assert_eq!(archived[..].total(), 10);
+}
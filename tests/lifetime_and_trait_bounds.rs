@@ -0,0 +1,42 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::marker::PhantomData;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<'a, T> {
+    elements: Vec<T>,
+    marker: PhantomData<&'a ()>,
+}
+
+// `T: 'a + Clone` is moved to the `where` clause and becomes
+// `T::Archived: 'a + Clone`, keeping the lifetime first.
+#[archive_impl(transform_bounds(T))]
+impl<'a, T: 'a + Clone> Foo<'a, T> {
+    #[archive_method(transform_return(T))]
+    pub fn first(&self) -> Option<T> {
+        self.elements.first().cloned()
+    }
+}
+
+pub fn call_archived<'a, T>(foo: &ArchivedFoo<'a, T>) -> Option<T::Archived>
+where
+    T: Archive,
+    T::Archived: 'a + Clone,
+{
+    foo.first()
+}
+
+#[test]
+fn lifetime_and_trait_bound() {
+    let foo = Foo {
+        elements: vec![7u32, 8],
+        marker: PhantomData,
+    };
+    assert_eq!(foo.first(), Some(7));
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(call_archived(archived), Some(7));
+}
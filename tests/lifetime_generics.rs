@@ -0,0 +1,38 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::marker::PhantomData;
+
+#[derive(Archive)]
+pub struct Foo<'a> {
+    value: u32,
+    marker: PhantomData<&'a [u8]>,
+}
+
+#[archive_impl]
+impl<'a> Foo<'a> {
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+pub fn call_archived(foo: &ArchivedFoo<'_>) -> u32 {
+    foo.value()
+}
+
+#[derive(Archive)]
+pub struct Bar<'a, 'b> {
+    value: u32,
+    marker: PhantomData<(&'a [u8], &'b [u8])>,
+}
+
+// The `'a: 'b` bound is moved to the `where` clause.
+#[archive_impl]
+impl<'a: 'b, 'b> Bar<'a, 'b> {
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+pub fn call_archived_bar<'a: 'b, 'b>(bar: &ArchivedBar<'a, 'b>) -> u32 {
+    bar.value()
+}
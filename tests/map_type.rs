@@ -0,0 +1,50 @@
+mod common;
+
+use rkyv::{Archive, Archived, Fallible, Serialize};
+use rkyv_impl::*;
+
+// A container that archives to a type of its own.
+pub struct MyWrapper<T>(pub T);
+
+#[repr(transparent)]
+pub struct MyArchivedWrapper<A>(pub A);
+
+impl<T: Archive> Archive for MyWrapper<T> {
+    type Archived = MyArchivedWrapper<T::Archived>;
+    type Resolver = T::Resolver;
+
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        self.0.resolve(pos, resolver, out.cast());
+    }
+}
+
+impl<T: Serialize<S>, S: Fallible + ?Sized> Serialize<S> for MyWrapper<T> {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T> {
+    wrapped: MyWrapper<T>,
+}
+
+#[archive_impl(add_bounds(T: Archive), map_type(MyWrapper<T> => MyArchivedWrapper<T::Archived>))]
+impl<T> Foo<T> {
+    // On `ArchivedFoo`, this returns `&MyArchivedWrapper<T::Archived>`.
+    pub fn wrapped(&self) -> &MyWrapper<T> {
+        &self.wrapped
+    }
+}
+
+#[test]
+fn custom_container_is_mapped() {
+    let foo = Foo {
+        wrapped: MyWrapper(3u32),
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+
+    let wrapped: &MyArchivedWrapper<Archived<u32>> = archived.wrapped();
+    assert_eq!(wrapped.0, foo.wrapped().0);
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T> {
+    value: T,
+}
+
+#[archive_impl(add_bounds(T: Archive))]
+impl<T> Foo<T> {
+    // On `ArchivedFoo`, the method keeps `'a` and requires `T::Archived: 'a`.
+    #[archive_method(transform_bounds(T), transform_return(T))]
+    #[allow(clippy::needless_lifetimes)]
+    pub fn borrow_checked<'a>(&'a self) -> &'a T
+    where
+        T: 'a,
+    {
+        &self.value
+    }
+}
+
+#[test]
+fn method_lifetime_bounds_transform_param() {
+    let foo = Foo {
+        value: "foo".to_string(),
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(archived.borrow_checked().as_str(), foo.borrow_checked());
+}
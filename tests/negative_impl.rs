@@ -0,0 +1,22 @@
+// Run with `RUSTFLAGS="--cfg nightly" cargo +nightly test`.
+#![cfg(nightly)]
+#![feature(negative_impls)]
+
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo;
+
+pub trait Marker {}
+
+// The `!` polarity is kept on both impls.
+#[archive_impl]
+impl !Marker for Foo {}
+
+// Fails to compile if the archived impl were positive.
+#[test]
+fn archived_impl_is_negative() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("test_files/nightly/negative-impl-polarity.rs");
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+#[archive_impl]
+impl Foo {
+    pub fn sum(&self) -> u32 {
+        self.elements.iter().sum()
+    }
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Wrapper(Foo);
+
+// `self.0` is an `ArchivedFoo` on `ArchivedWrapper`, so delegating works as
+// long as the inner method is also generated for `ArchivedFoo`.
+#[archive_impl]
+impl Wrapper {
+    pub fn sum(&self) -> u32 {
+        self.0.sum()
+    }
+}
+
+#[test]
+fn delegates_to_archived_inner() {
+    let wrapper = Wrapper(Foo {
+        elements: vec![1, 2, 3],
+    });
+    let bytes = common::archive(&wrapper);
+    let archived = bytes.root();
+    assert_eq!(archived.sum(), 6);
+    assert_eq!(wrapper.sum(), archived.sum());
+}
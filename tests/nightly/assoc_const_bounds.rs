@@ -0,0 +1,48 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+pub trait Dims {
+    type const DIMS: usize;
+
+    fn dims(&self) -> usize {
+        Self::DIMS
+    }
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Point;
+
+impl Dims for Point {
+    type const DIMS: usize = 3;
+}
+
+impl Dims for ArchivedPoint {
+    type const DIMS: usize = 3;
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T> {
+    point: T,
+}
+
+// `T` becomes `T::Archived` while the `DIMS` binding and its expression are
+// kept as written.
+#[archive_impl(transform_bounds(T), add_bounds(T: Archive))]
+impl<T> Foo<T>
+where
+    T: Dims<DIMS = { 3 }>,
+{
+    pub fn dims(&self) -> usize {
+        self.point.dims()
+    }
+}
+
+#[test]
+fn assoc_const_binding_is_preserved() {
+    let foo = Foo { point: Point };
+    assert_eq!(foo.dims(), 3);
+
+    let bytes = rkyv::to_bytes::<_, 256>(&foo).unwrap();
+    let archived = unsafe { rkyv::archived_root::<Foo<Point>>(&bytes) };
+    assert_eq!(archived.dims(), 3);
+}
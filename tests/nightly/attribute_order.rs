@@ -0,0 +1,64 @@
+use rkyv::{Archive, Serialize};
+use rkyv_impl::archive_impl;
+
+pub trait Tagged {}
+
+// Adds `impl Tagged` for the self type of each `impl` it's applied to.
+macro_rules! tagged {
+    attr() ($(#[$attr:meta])* impl $ty:ty { $($body:tt)* }) => {
+        $(#[$attr])*
+        impl $ty { $($body)* }
+
+        impl Tagged for $ty {}
+    };
+}
+
+fn is_tagged<T: Tagged + ?Sized>() -> bool {
+    true
+}
+
+#[derive(Archive, Serialize)]
+pub struct Foo {
+    value: u32,
+}
+
+// `archive_impl` runs first, and both generated blocks keep `#[tagged]`.
+#[archive_impl]
+#[tagged]
+impl Foo {
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[derive(Archive, Serialize)]
+pub struct Bar {
+    value: u32,
+}
+
+// `tagged` runs first and only sees the original `impl`, so only `Bar` is
+// tagged.
+#[tagged]
+#[archive_impl]
+impl Bar {
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[test]
+fn archive_impl_first() {
+    let foo = Foo { value: 1 };
+    let bytes = crate::common::archive(&foo);
+    assert_eq!(foo.value(), bytes.root().value());
+    assert!(is_tagged::<Foo>());
+    assert!(is_tagged::<ArchivedFoo>());
+}
+
+#[test]
+fn archive_impl_second() {
+    let bar = Bar { value: 1 };
+    let bytes = crate::common::archive(&bar);
+    assert_eq!(bar.value(), bytes.root().value());
+    assert!(is_tagged::<Bar>());
+}
@@ -0,0 +1,36 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+// The `T: Archive` bound is given explicitly instead of being added by
+// `transform_bounds`.
+#[archive_impl(transform_bounds(T), no_archive_bounds, add_bounds(T: Archive<Archived = T>))]
+impl<T> Foo<T>
+where
+    T: PartialEq,
+{
+    pub fn has_adjacent_duplicates(&self) -> bool {
+        self.elements.windows(2).any(|pair| pair[0] == pair[1])
+    }
+
+    #[archive_method(transform_bounds(S), add_bounds(S: Archive))]
+    pub fn count_defaults<S>(&self) -> usize
+    where
+        S: Default + PartialEq,
+    {
+        0
+    }
+}
+
+pub fn call_archived<T, S>(foo: &ArchivedFoo<T>) -> bool
+where
+    T: Archive<Archived = T> + PartialEq,
+    S: Archive,
+    S::Archived: Default + PartialEq,
+{
+    foo.has_adjacent_duplicates() || foo.count_defaults::<S>() > 0
+}
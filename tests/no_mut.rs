@@ -0,0 +1,42 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    value: u32,
+    reads: u32,
+}
+
+#[archive_impl(no_mut)]
+impl Foo {
+    // The archived copy takes `&self`, which its body allows.
+    pub fn value(&mut self) -> u32 {
+        self.value
+    }
+
+    #[allow(clippy::needless_arbitrary_self_type)]
+    pub fn value_explicit(self: &mut Self) -> u32 {
+        self.value
+    }
+
+    #[archive_method(skip)]
+    pub fn read(&mut self) -> u32 {
+        self.reads += 1;
+        self.value
+    }
+}
+
+#[test]
+fn mut_receivers_become_shared() {
+    let mut foo = Foo { value: 6, reads: 0 };
+    assert_eq!(foo.read(), 6);
+    assert_eq!(foo.value(), 6);
+    assert_eq!(foo.value_explicit(), 6);
+
+    let bytes = common::archive(&foo);
+    let archived: &ArchivedFoo = bytes.root();
+    assert_eq!(archived.value(), 6);
+    assert_eq!(archived.value_explicit(), 6);
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+pub trait Named {
+    fn name() -> String;
+}
+
+impl Named for (String, u32) {
+    fn name() -> String {
+        "original".into()
+    }
+}
+
+impl Named for (rkyv::string::ArchivedString, u32) {
+    fn name() -> String {
+        "archived key".into()
+    }
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T, V> {
+    key: T,
+    value: V,
+}
+
+// `transform_bounds` applies to every occurrence of `T` and `V` on the `impl`,
+// except for the preserved `V: Send`.
+#[archive_impl(transform_bounds(T, V), preserve_bounds(V: Send))]
+impl<T, V> Foo<T, V>
+where
+    T: PartialEq,
+    V: PartialEq + Send,
+{
+    pub fn same(&self, other: &Self) -> bool {
+        self.key == other.key && self.value == other.value
+    }
+
+    // Only `T` is transformed in the method's own bounds.
+    #[archive_method(transform_bounds(T), archived_body = { <(T::Archived, V)>::name() })]
+    pub fn pair_name(&self) -> String
+    where
+        (T, V): Named,
+    {
+        <(T, V)>::name()
+    }
+}
+
+pub fn call_archived<T, V>(foo: &ArchivedFoo<T, V>) -> (bool, String)
+where
+    T: Archive,
+    V: Archive + Send,
+    T::Archived: PartialEq,
+    V::Archived: PartialEq,
+    (T::Archived, V): Named,
+{
+    (foo.same(foo), foo.pair_name())
+}
+
+#[test]
+fn partial_transform() {
+    let foo = Foo {
+        key: String::from("key"),
+        value: 2u32,
+    };
+    assert!(foo.same(&foo));
+    assert_eq!(foo.pair_name(), "original");
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(call_archived(archived), (true, "archived key".into()));
+}
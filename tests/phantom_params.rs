@@ -0,0 +1,30 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::marker::PhantomData;
+
+#[derive(Archive)]
+pub struct Foo<T, U> {
+    marker: PhantomData<T>,
+    elements: Vec<U>,
+}
+
+// `T` is only a marker, so it's left out of `transform_bounds` and doesn't get
+// a `T: Archive` bound.
+#[archive_impl(transform_bounds(U))]
+impl<T, U: Clone> Foo<T, U> {
+    #[archive_method(transform_return(U))]
+    pub fn first(&self) -> Option<U> {
+        self.elements.first().cloned()
+    }
+}
+
+// Not `Archive`.
+pub struct Marker;
+
+pub fn call_archived<U>(foo: &ArchivedFoo<Marker, U>) -> Option<U::Archived>
+where
+    U: Archive,
+    U::Archived: Clone,
+{
+    foo.first()
+}
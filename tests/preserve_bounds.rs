@@ -0,0 +1,39 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+// `T: Send` stays literal, while `T: PartialEq` becomes
+// `T::Archived: PartialEq`.
+#[archive_impl(transform_bounds(T), preserve_bounds(T: Send))]
+impl<T> Foo<T>
+where
+    T: PartialEq + Send,
+{
+    pub fn has_adjacent_duplicates(&self) -> bool {
+        self.elements.windows(2).any(|pair| pair[0] == pair[1])
+    }
+
+    #[archive_method(transform_bounds(S), preserve_bounds(S: Clone))]
+    pub fn contains_any<S>(&self, _values: &[S]) -> bool
+    where
+        S: Clone + Default,
+    {
+        false
+    }
+}
+
+// `T::Archived` is not required to be `Send`, and `T` is not required to be
+// `PartialEq`.
+pub fn call_archived<T, S>(foo: &ArchivedFoo<T>, values: &[S]) -> bool
+where
+    T: Archive + Send,
+    T::Archived: PartialEq,
+    S: Archive + Clone,
+    S::Archived: Default,
+{
+    foo.has_adjacent_duplicates() || foo.contains_any(values)
+}
@@ -0,0 +1,33 @@
+use rkyv_impl::*;
+
+pub mod a {
+    use rkyv::Archive;
+
+    #[derive(Archive)]
+    pub struct Foo {
+        pub elements: Vec<u32>,
+    }
+}
+
+// The generated impl names `crate::ArchivedFoo`, so it must be re-exported
+// alongside `Foo`.
+pub use a::{ArchivedFoo, Foo};
+
+#[archive_impl]
+impl crate::Foo {
+    pub fn first(&self) -> Option<u32> {
+        self.elements.first().copied()
+    }
+}
+
+// Naming the defining module works without a re-export.
+#[archive_impl]
+impl a::Foo {
+    pub fn last(&self) -> Option<u32> {
+        self.elements.last().copied()
+    }
+}
+
+pub fn call_archived(foo: &ArchivedFoo) -> (Option<u32>, Option<u32>) {
+    (foo.first(), foo.last())
+}
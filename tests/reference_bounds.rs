@@ -0,0 +1,65 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Bag {
+    items: Vec<u32>,
+}
+
+impl<'a> IntoIterator for &'a Bag {
+    type Item = &'a u32;
+    type IntoIter = std::slice::Iter<'a, u32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ArchivedBag {
+    type Item = &'a u32;
+    type IntoIter = std::slice::Iter<'a, u32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T> {
+    inner: T,
+}
+
+// `&'a T` becomes `&'a T::Archived` in the bounded type.
+#[archive_impl(transform_bounds(T))]
+impl<T> Foo<T>
+where
+    for<'a> &'a T: IntoIterator<Item = &'a u32>,
+{
+    pub fn sum(&self) -> u32 {
+        (&self.inner).into_iter().sum()
+    }
+}
+
+pub fn call_archived<T>(foo: &ArchivedFoo<T>) -> u32
+where
+    T: Archive,
+    for<'a> &'a T::Archived: IntoIterator<Item = &'a u32>,
+{
+    foo.sum()
+}
+
+#[test]
+fn reference_bounded_type() {
+    let foo = Foo {
+        inner: Bag {
+            items: vec![1, 2, 3],
+        },
+    };
+    assert_eq!(foo.sum(), 6);
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(call_archived(archived), 6);
+}
@@ -0,0 +1,33 @@
+use rkyv::{Archive, Serialize};
+use rkyv_impl::*;
+
+#[derive(Archive, Serialize)]
+#[archive_attr(repr(C))]
+pub struct Foo {
+    x: u32,
+    y: u32,
+}
+
+#[archive_impl]
+impl Foo {
+    pub fn sum(&self) -> u32 {
+        self.x + self.y
+    }
+}
+
+// Stands in for a function on the other side of an FFI boundary, which only
+// sees a pointer into the shared buffer.
+extern "C" fn sum_archived(foo: *const ArchivedFoo) -> u32 {
+    unsafe { &*foo }.sum()
+}
+
+#[test]
+fn repr_c_archived_through_raw_pointer() {
+    let foo = Foo { x: 2, y: 5 };
+    let bytes = rkyv::to_bytes::<_, 256>(&foo).unwrap();
+
+    // The root object is at the end of the buffer.
+    let pos = bytes.len() - std::mem::size_of::<ArchivedFoo>();
+    let ptr = unsafe { bytes.as_ptr().add(pos) }.cast::<ArchivedFoo>();
+    assert_eq!(sum_archived(ptr), 7);
+}
@@ -0,0 +1,53 @@
+mod common;
+
+pub mod outer {
+    pub mod inner {
+        use rkyv::Archive;
+        use rkyv_impl::archive_impl;
+
+        #[derive(Archive, rkyv::Serialize)]
+        pub struct Foo {
+            pub field: Vec<u32>,
+        }
+
+        #[archive_impl]
+        impl Foo {
+            pub(crate) fn get_slice(&self) -> &[u32] {
+                &self.field
+            }
+
+            pub(super) fn get_first(&self) -> Option<&u32> {
+                self.field.first()
+            }
+
+            pub(in crate::outer) fn get_last(&self) -> Option<&u32> {
+                self.field.last()
+            }
+        }
+    }
+
+    // `pub(super)` and `pub(in crate::outer)` methods are visible here, and
+    // would not be if the archived copies lost their visibility.
+    pub fn call_archived(foo: &inner::ArchivedFoo) -> Option<&u32> {
+        let _: &[u32] = foo.get_slice();
+        let _: Option<&u32> = foo.get_last();
+        foo.get_first()
+    }
+
+    pub fn call_original(foo: &inner::Foo) -> Option<&u32> {
+        let _: Option<&u32> = foo.get_last();
+        foo.get_first()
+    }
+}
+
+#[test]
+fn visibility_is_preserved() {
+    let foo = outer::inner::Foo {
+        field: vec![1, 2, 3],
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+
+    assert_eq!(foo.get_slice(), archived.get_slice());
+    assert_eq!(outer::call_original(&foo), outer::call_archived(archived));
+}
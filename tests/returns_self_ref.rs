@@ -0,0 +1,43 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+#[archive_impl(skip_mut_methods)]
+impl Foo {
+    // Returns `&ArchivedFoo` on the archived type.
+    pub fn as_ref_self(&self) -> &Self {
+        self
+    }
+
+    // A fluent setter needs `&mut self`, so it's skipped.
+    pub fn push(&mut self, value: u32) -> &mut Self {
+        self.elements.push(value);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
+
+pub fn call_archived(foo: &ArchivedFoo) -> usize {
+    let same: &ArchivedFoo = foo.as_ref_self();
+    same.len()
+}
+
+#[test]
+fn chained_on_original() {
+    let mut foo = Foo {
+        elements: Vec::new(),
+    };
+    foo.push(1).push(2);
+    assert_eq!(foo.as_ref_self().len(), 2);
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    value: u32,
+}
+
+impl Foo {
+    pub fn kind() -> &'static str {
+        "original"
+    }
+}
+
+impl ArchivedFoo {
+    pub fn kind() -> &'static str {
+        "archived"
+    }
+}
+
+// `Foo::kind()` refers to `Foo` in both impls.
+#[archive_impl]
+impl Foo {
+    pub fn describe(&self) -> String {
+        let kind = Foo::kind();
+        format!("{kind} {}", self.value)
+    }
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Bar {
+    value: u32,
+}
+
+impl Bar {
+    pub fn kind() -> &'static str {
+        "original"
+    }
+}
+
+impl ArchivedBar {
+    pub fn kind() -> &'static str {
+        "archived"
+    }
+}
+
+pub mod other {
+    pub struct Bar;
+
+    impl Bar {
+        pub fn kind() -> &'static str {
+            "other"
+        }
+    }
+}
+
+pub trait Kind {
+    fn trait_kind() -> &'static str;
+}
+
+impl Kind for Bar {
+    fn trait_kind() -> &'static str {
+        "original"
+    }
+}
+
+impl Kind for ArchivedBar {
+    fn trait_kind() -> &'static str {
+        "archived"
+    }
+}
+
+// `Bar::kind()` becomes `ArchivedBar::kind()` in the generated `impl`, while
+// `other::Bar::kind()` names a different type and the qualified path is left
+// alone.
+#[archive_impl(rewrite_self_calls)]
+impl Bar {
+    pub fn describe(&self) -> String {
+        let kind = Bar::kind();
+        format!("{kind} {}", self.value)
+    }
+
+    pub fn other_kind(&self) -> &'static str {
+        other::Bar::kind()
+    }
+
+    pub fn qualified_kind(&self) -> &'static str {
+        <Bar as Kind>::trait_kind()
+    }
+}
+
+#[test]
+fn self_calls_are_kept() {
+    let foo = Foo { value: 1 };
+    assert_eq!(foo.describe(), "original 1");
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(archived.describe(), "original 1");
+}
+
+#[test]
+fn self_calls_are_rewritten() {
+    let bar = Bar { value: 2 };
+    assert_eq!(bar.describe(), "original 2");
+
+    let bytes = common::archive(&bar);
+    let archived = bytes.root();
+    assert_eq!(archived.describe(), "archived 2");
+    assert_eq!(archived.other_kind(), "other");
+    assert_eq!(archived.qualified_kind(), "original");
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub enum Shape {
+    Circle(u32),
+    Rect { w: u32, h: u32 },
+}
+
+// Tuple and struct variant patterns are rewritten to `ArchivedShape`.
+#[archive_impl(rewrite_self_calls)]
+impl Shape {
+    pub fn area(&self) -> u32 {
+        match self {
+            Shape::Circle(r) => 3 * r * r,
+            Shape::Rect { w, h } => w * h,
+        }
+    }
+
+    pub fn is_unit_rect(&self) -> bool {
+        match self {
+            Shape::Rect { w: 1, h: 1 } => true,
+            Shape::Circle(_) | Shape::Rect { .. } => false,
+        }
+    }
+}
+
+#[test]
+fn variant_patterns_are_rewritten() {
+    for shape in [Shape::Circle(2), Shape::Rect { w: 2, h: 3 }] {
+        let bytes = common::archive(&shape);
+        let archived = bytes.root();
+        assert_eq!(archived.area(), shape.area());
+        assert_eq!(archived.is_unit_rect(), shape.is_unit_rect());
+    }
+}
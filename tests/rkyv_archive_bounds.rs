@@ -0,0 +1,49 @@
+mod common;
+
+use rkyv::{Archive, Deserialize, Serialize};
+use rkyv_impl::*;
+
+// A recursive type needs rkyv's `bound` attribute, since the bounds of the
+// omitted field would otherwise be recursive. The bounds on the generated
+// `impl` are only those from `archive_impl`.
+#[derive(Archive, Serialize, Deserialize)]
+#[archive(bound(
+    serialize = "__S: rkyv::ser::ScratchSpace + rkyv::ser::Serializer",
+    deserialize = "__D: rkyv::Fallible"
+))]
+pub struct Node<T> {
+    value: T,
+    #[omit_bounds]
+    children: Vec<Node<T>>,
+}
+
+#[archive_impl(transform_bounds(T))]
+impl<T: Copy + Into<u64>> Node<T> {
+    pub fn count(&self) -> usize {
+        1 + self.children.iter().map(|c| c.count()).sum::<usize>()
+    }
+
+    pub fn sum(&self) -> u64 {
+        self.value.into() + self.children.iter().map(|c| c.sum()).sum::<u64>()
+    }
+}
+
+#[test]
+fn recursive_archived_methods() {
+    let leaf = |value| Node {
+        value,
+        children: vec![],
+    };
+    let tree = Node {
+        value: 1u32,
+        children: vec![leaf(2), leaf(3)],
+    };
+    assert_eq!((tree.count(), tree.sum()), (3, 6));
+
+    let bytes = common::archive(&tree);
+    let archived = bytes.root();
+    assert_eq!((archived.count(), archived.sum()), (3, 6));
+
+    let deserialized: Node<u32> = archived.deserialize(&mut rkyv::Infallible).unwrap();
+    assert_eq!(deserialized.sum(), 6);
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+pub trait Elements {
+    type Item;
+
+    fn first(&self) -> Option<&Self::Item>;
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+// `Self::Item` resolves against each impl's own `type Item`.
+#[archive_impl]
+impl Elements for Foo {
+    type Item = u32;
+
+    fn first(&self) -> Option<&Self::Item> {
+        self.elements.first()
+    }
+}
+
+#[test]
+fn archived_first() {
+    let foo = Foo {
+        elements: vec![1, 2],
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(foo.first(), archived.first());
+}
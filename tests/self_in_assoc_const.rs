@@ -0,0 +1,31 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+// `compute` is copied too, so `Self::compute()` also resolves on
+// `ArchivedFoo`.
+#[archive_impl]
+impl Foo {
+    pub const CAPACITY: usize = Self::compute();
+
+    pub const fn compute() -> usize {
+        4
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.elements.len() >= Self::CAPACITY
+    }
+}
+
+pub fn call_archived(foo: &ArchivedFoo) -> bool {
+    foo.is_full()
+}
+
+#[test]
+fn consts_match() {
+    assert_eq!(Foo::CAPACITY, ArchivedFoo::CAPACITY);
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    value: u32,
+}
+
+#[archive_impl]
+impl Foo {
+    pub fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    pub fn empty_list(&self) -> usize {
+        Vec::<Self>::new().len()
+    }
+}
+
+#[test]
+fn self_resolves_per_impl() {
+    let foo = Foo { value: 1 };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(foo.type_name(), std::any::type_name::<Foo>());
+    assert_eq!(archived.type_name(), std::any::type_name::<ArchivedFoo>());
+    assert_eq!(foo.empty_list(), archived.empty_list());
+}
@@ -0,0 +1,27 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    field: T,
+}
+
+// In the archived impl, `Self` is `ArchivedFoo<T>`, so the bound becomes
+// `T::Archived: AsRef<ArchivedFoo<T>>`.
+#[archive_impl(transform_bounds(T))]
+impl<T> Foo<T>
+where
+    T: AsRef<Self>,
+{
+    pub fn field_as_self(&self) -> &Self {
+        self.field.as_ref()
+    }
+}
+
+pub fn call_archived<T>(foo: &ArchivedFoo<T>) -> &ArchivedFoo<T>
+where
+    T: Archive,
+    T::Archived: AsRef<ArchivedFoo<T>>,
+{
+    foo.field_as_self()
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    value: u32,
+}
+
+impl PartialEq<Foo> for u32 {
+    fn eq(&self, other: &Foo) -> bool {
+        *self == other.value
+    }
+}
+
+impl PartialEq<ArchivedFoo> for u32 {
+    fn eq(&self, other: &ArchivedFoo) -> bool {
+        *self == other.value
+    }
+}
+
+// The method bound moves to the `where` clause, where `Self` still means
+// `ArchivedFoo` in the generated `impl`.
+#[archive_impl]
+impl Foo {
+    pub fn cmp_with<U: PartialEq<Self>>(&self, u: &U) -> bool {
+        *u == *self
+    }
+}
+
+#[test]
+fn self_in_method_bound() {
+    let foo = Foo { value: 4 };
+    assert!(foo.cmp_with(&4u32));
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert!(archived.cmp_with(&4u32));
+    assert!(!archived.cmp_with(&5u32));
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+pub trait Compute {
+    type Output;
+
+    fn compute(&self) -> Self::Output;
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    value: u32,
+}
+
+impl Compute for Foo {
+    type Output = u32;
+
+    fn compute(&self) -> u32 {
+        self.value
+    }
+}
+
+impl Compute for ArchivedFoo {
+    type Output = u64;
+
+    fn compute(&self) -> u64 {
+        u64::from(self.value)
+    }
+}
+
+// `Self` is rebound in the generated impl, so this bound means
+// `<ArchivedFoo as Compute>::Output: ToString` there. Inherent impls need the
+// qualified form, since `Self::Output` is ambiguous (E0223).
+#[archive_impl]
+impl Foo
+where
+    Self: Compute,
+    <Self as Compute>::Output: ToString,
+{
+    pub fn describe(&self) -> String {
+        self.compute().to_string()
+    }
+}
+
+#[test]
+fn resolves_per_impl() {
+    let foo = Foo { value: 2 };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(foo.describe(), archived.describe());
+    assert_eq!(archived.compute(), 2u64);
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize, Clone)]
+#[archive_attr(derive(Clone))]
+pub struct Foo {
+    value: u32,
+}
+
+// `Self: Clone` means `ArchivedFoo: Clone` in the generated `impl`.
+#[archive_impl]
+impl Foo {
+    pub fn duplicate(&self) -> (Self, Self)
+    where
+        Self: Clone,
+    {
+        (self.clone(), self.clone())
+    }
+}
+
+#[test]
+fn self_where_clause() {
+    let foo = Foo { value: 3 };
+    let (a, b) = foo.duplicate();
+    assert_eq!(a.value + b.value, 6);
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    let (a, b): (ArchivedFoo, ArchivedFoo) = archived.duplicate();
+    assert_eq!(a.value + b.value, 6);
+}
@@ -0,0 +1,42 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    name: String,
+    count: u32,
+}
+
+#[archive_impl(skip_by_value_self)]
+impl Foo {
+    // The archived fields can't be moved out of the buffer.
+    pub fn into_parts(self) -> (String, u32) {
+        (self.name, self.count)
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+// Would conflict if `into_parts` had been copied.
+impl ArchivedFoo {
+    pub fn into_parts(&self) -> (&str, u32) {
+        (self.name.as_str(), self.count)
+    }
+}
+
+pub fn call_archived(foo: &ArchivedFoo) -> (&str, u32) {
+    let _: u32 = foo.count();
+    foo.into_parts()
+}
+
+#[test]
+fn original_keeps_by_value_methods() {
+    let foo = Foo {
+        name: "foo".into(),
+        count: 1,
+    };
+    assert_eq!(foo.count(), 1);
+    assert_eq!(foo.into_parts(), ("foo".into(), 1));
+}
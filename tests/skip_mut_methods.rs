@@ -0,0 +1,42 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo {
+    elements: Vec<u32>,
+    count: u32,
+}
+
+#[archive_impl(skip_mut_methods)]
+impl Foo {
+    // `ArchivedVec` has no `push`, so this can't be copied.
+    pub fn push(&mut self, value: u32) {
+        self.elements.push(value);
+    }
+
+    pub fn get_slice(&self) -> &[u32] {
+        &self.elements
+    }
+
+    #[archive_method(keep)]
+    pub fn count_mut(&mut self) -> &mut u32 {
+        &mut self.count
+    }
+}
+
+pub fn call_archived(foo: &mut ArchivedFoo) -> &mut u32 {
+    let _: &[u32] = foo.get_slice();
+    foo.count_mut()
+}
+
+#[test]
+fn original_keeps_mut_methods() {
+    let mut foo = Foo {
+        elements: Vec::new(),
+        count: 0,
+    };
+    foo.push(1);
+    *foo.count_mut() += 1;
+    assert_eq!(foo.get_slice(), &[1]);
+    assert_eq!(foo.count, 1);
+}
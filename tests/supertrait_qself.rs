@@ -0,0 +1,43 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    field: u32,
+}
+
+pub trait Named {
+    fn name() -> &'static str;
+}
+
+pub trait Describe: Named {
+    fn describe(&self) -> String;
+}
+
+#[archive_impl]
+impl Named for Foo {
+    fn name() -> &'static str {
+        "Foo"
+    }
+}
+
+// Bodies are copied verbatim, so `<Self as Named>` refers to `ArchivedFoo`'s
+// impl in the generated block.
+#[archive_impl]
+impl Describe for Foo {
+    fn describe(&self) -> String {
+        format!("{} {}", <Self as Named>::name(), self.field)
+    }
+}
+
+#[test]
+fn supertrait_call_resolves_per_impl() {
+    let foo = Foo { field: 1 };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+
+    assert_eq!(foo.describe(), "Foo 1");
+    assert_eq!(archived.describe(), "Foo 1");
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::panic::Location;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo {
+    elements: Vec<u32>,
+}
+
+#[archive_impl(track_caller)]
+impl Foo {
+    pub const BODY_LINE: u32 = line!() + 3;
+
+    pub fn location(&self) -> &'static Location<'static> {
+        Location::caller()
+    }
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Bar;
+
+#[archive_impl]
+impl Bar {
+    #[track_caller]
+    pub fn location(&self) -> &'static Location<'static> {
+        Location::caller()
+    }
+}
+
+#[test]
+fn injected_on_archived_methods() {
+    let foo = Foo { elements: vec![1] };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    let expected_line = line!() + 1;
+    let location = archived.location();
+    assert_eq!(location.line(), expected_line);
+
+    // The original method is left alone, so it reports its own body.
+    assert_eq!(foo.location().line(), Foo::BODY_LINE);
+}
+
+#[test]
+fn copied_to_archived_methods() {
+    let bytes = common::archive(&Bar);
+    let archived = bytes.root();
+    let expected_line = line!() + 1;
+    let location = archived.location();
+    assert_eq!(location.line(), expected_line);
+}
@@ -0,0 +1,37 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+#[archive_impl(add_bounds(T: Archive))]
+impl<T> Foo<T> {
+    // On `ArchivedFoo`, this takes a `&T::Archived`, returns an
+    // `Option<&T::Archived>`, and requires `T::Archived: PartialEq`.
+    #[archive_method(transform_all(T))]
+    pub fn find(&self, value: &T) -> Option<&T>
+    where
+        T: PartialEq,
+    {
+        self.elements.iter().find(|e| *e == value)
+    }
+}
+
+pub fn call_archived<'a, T>(foo: &'a ArchivedFoo<T>, value: &T::Archived) -> Option<&'a T::Archived>
+where
+    T: Archive,
+    T::Archived: PartialEq,
+{
+    foo.find(value)
+}
+
+#[test]
+fn original_is_unchanged() {
+    let foo = Foo {
+        elements: vec![1, 2],
+    };
+    assert_eq!(foo.find(&2), Some(&2));
+    assert_eq!(foo.find(&3), None);
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::fmt::Debug;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T, const N: usize> {
+    elements: [T; N],
+}
+
+// Only the array's element type is transformed; the length `N` is left alone.
+#[archive_impl(transform_bounds(T), add_bounds(T: Archive))]
+impl<T, const N: usize> Foo<T, N>
+where
+    [T; N]: Debug,
+{
+    pub fn describe(&self) -> String {
+        format!("{:?}", self.elements)
+    }
+}
+
+pub fn call_archived<T, const N: usize>(foo: &ArchivedFoo<T, N>) -> String
+where
+    T: Archive,
+    [T::Archived; N]: Debug,
+{
+    foo.describe()
+}
+
+#[test]
+fn describe_archived() {
+    let foo = Foo {
+        elements: [1u32, 2],
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert_eq!(call_archived(archived), foo.describe());
+}
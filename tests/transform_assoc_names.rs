@@ -0,0 +1,43 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+pub trait Freeze {
+    type Frozen;
+}
+
+impl Freeze for u32 {
+    type Frozen = u64;
+}
+
+#[derive(Archive)]
+pub struct Foo<T, U> {
+    first: Vec<T>,
+    second: Vec<U>,
+}
+
+// `T` becomes `T::Archived` and `U` becomes `U::Frozen`.
+#[archive_impl(
+    transform_bounds(T, U),
+    assoc(T = "Archived", U = "Frozen"),
+    no_archive_bounds,
+    add_bounds(T: Archive, U: Archive + Freeze),
+)]
+impl<T, U> Foo<T, U>
+where
+    T: PartialEq,
+    U: Default,
+{
+    pub fn lens(&self) -> (usize, usize) {
+        (self.first.len(), self.second.len())
+    }
+}
+
+pub fn call_archived<T, U>(foo: &ArchivedFoo<T, U>) -> (usize, usize)
+where
+    T: Archive,
+    T::Archived: PartialEq,
+    U: Archive + Freeze,
+    U::Frozen: Default,
+{
+    foo.lens()
+}
@@ -0,0 +1,34 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::iter::Sum;
+
+#[derive(Archive)]
+pub struct Foo<T, S> {
+    elements: Vec<T>,
+    extra: Vec<S>,
+}
+
+#[archive_impl(transform_bounds(T, S))]
+impl<T, S> Foo<T, S> {
+    // Same as `#[archive_method(transform_bounds(T, S))]`.
+    #[archive_method(transform_bounds_from_impl)]
+    pub fn total<R>(&self) -> R
+    where
+        T: Clone,
+        S: Clone,
+        R: Sum<T> + Sum<S> + std::ops::Add<Output = R>,
+    {
+        self.elements.iter().cloned().sum::<R>() + self.extra.iter().cloned().sum::<R>()
+    }
+}
+
+pub fn call_archived<T, S, R>(foo: &ArchivedFoo<T, S>) -> R
+where
+    T: Archive,
+    S: Archive,
+    T::Archived: Clone,
+    S::Archived: Clone,
+    R: Sum<T::Archived> + Sum<S::Archived> + std::ops::Add<Output = R>,
+{
+    foo.total::<R>()
+}
@@ -0,0 +1,28 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+#[archive_impl(add_bounds(T: Archive))]
+impl<T> Foo<T> {
+    // `T` is declared on the impl, but the method's own bound still gets
+    // transformed.
+    #[archive_method(transform_bounds(T))]
+    pub fn has_adjacent_duplicates(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.elements.windows(2).any(|pair| pair[0] == pair[1])
+    }
+}
+
+pub fn call_archived<T>(foo: &ArchivedFoo<T>) -> bool
+where
+    T: Archive,
+    T::Archived: PartialEq,
+{
+    foo.has_adjacent_duplicates()
+}
@@ -0,0 +1,45 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::iter::Sum;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+#[archive_impl(transform_bounds(T), transform_methods(sum, clone_first))]
+impl<T> Foo<T> {
+    pub fn sum<S>(&self) -> S
+    where
+        T: Clone,
+        S: Sum<T>,
+    {
+        self.elements.iter().cloned().sum()
+    }
+
+    pub fn clone_first(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        None
+    }
+
+    // Not listed, so this stays `T: Default`.
+    pub fn is_empty(&self) -> bool
+    where
+        T: Default,
+    {
+        self.elements.is_empty()
+    }
+}
+
+pub fn call_archived<T, S>(foo: &ArchivedFoo<T>) -> bool
+where
+    T: Archive + Default,
+    T::Archived: Clone,
+    S: Sum<T::Archived>,
+{
+    let _: S = foo.sum();
+    let _ = foo.clone_first();
+    foo.is_empty()
+}
@@ -0,0 +1,39 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<R, T> {
+    field1: R,
+    field2: Option<T>,
+}
+
+pub trait MakeBar {
+    type Bar;
+
+    fn make_bar(&self) -> Self::Bar;
+}
+
+// `T` is nested inside of `Option<T>`, and `R` is inside of a qualified path.
+// With multiple params, each occurrence must only be transformed once.
+#[archive_impl(transform_bounds(R, T))]
+impl<R, T> Foo<R, T>
+where
+    R: MakeBar,
+    <R as MakeBar>::Bar: Into<u32>,
+    Option<T>: Clone,
+{
+    pub fn get_bar_u32(&self) -> u32 {
+        self.field1.make_bar().into()
+    }
+}
+
+pub fn call_archived<R, T>(foo: ArchivedFoo<R, T>) -> u32
+where
+    R: Archive,
+    T: Archive,
+    R::Archived: MakeBar,
+    <R::Archived as MakeBar>::Bar: Into<u32>,
+    Option<T::Archived>: Clone,
+{
+    foo.get_bar_u32()
+}
@@ -0,0 +1,35 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::marker::PhantomData;
+
+pub trait Container {
+    type Inner;
+}
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    count: u32,
+    marker: PhantomData<T>,
+}
+
+#[archive_impl]
+impl<T: Container> Foo<T> {
+    // The archived method gets `T::Inner: Archive` and
+    // `U: From<<T::Inner as Archive>::Archived>`.
+    #[archive_method(transform_bounds(T: path("Inner::Archived")))]
+    pub fn count_if_convertible<U>(&self) -> u32
+    where
+        U: From<T>,
+    {
+        self.count
+    }
+}
+
+pub fn call_archived<T, U>(foo: &ArchivedFoo<T>) -> u32
+where
+    T: Container,
+    T::Inner: Archive,
+    U: From<<T::Inner as Archive>::Archived>,
+{
+    foo.count_if_convertible::<U>()
+}
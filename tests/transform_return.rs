@@ -0,0 +1,40 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+#[archive_impl(add_bounds(T: Archive))]
+impl<T> Foo<T> {
+    #[archive_method(transform_return(T))]
+    pub fn first(&self) -> Option<&T> {
+        self.elements.first()
+    }
+
+    // Explicit lifetimes are preserved as well.
+    #[archive_method(transform_return(T))]
+    pub fn get<'a>(&'a self, index: &usize) -> Option<&'a T> {
+        self.elements.get(*index)
+    }
+}
+
+pub fn call_archived<T: Archive>(foo: &ArchivedFoo<T>) -> Option<&T::Archived> {
+    let _: Option<&T::Archived> = foo.get(&0);
+    foo.first()
+}
+
+#[test]
+fn archived_return_type() {
+    let foo = Foo {
+        elements: vec![1u32, 2, 3],
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+
+    assert_eq!(foo.first(), archived.first());
+    assert_eq!(foo.get(&2), archived.get(&2));
+}
@@ -0,0 +1,38 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::marker::PhantomData;
+
+#[derive(Archive)]
+pub struct Foo {
+    value: u32,
+}
+
+#[derive(Archive)]
+pub struct Tagged<T> {
+    id: u32,
+    marker: PhantomData<T>,
+}
+
+// Generates `impl ArchivedTagged<ArchivedFoo>`.
+#[archive_impl(transform_self_args(Foo))]
+impl Tagged<Foo> {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+pub fn call_archived(tagged: &ArchivedTagged<ArchivedFoo>) -> u32 {
+    tagged.id()
+}
+
+// Without the option, only the outer type is renamed.
+#[archive_impl]
+impl Tagged<u64> {
+    pub fn id_u64(&self) -> u32 {
+        self.id
+    }
+}
+
+pub fn call_archived_u64(tagged: &ArchivedTagged<u64>) -> u32 {
+    tagged.id_u64()
+}
@@ -0,0 +1,30 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+#[archive_impl(transform_bounds(T))]
+impl<T> Foo<T> {
+    // `T` is transformed in trait arguments too, so the archived method has
+    // `U: From<T::Archived>`.
+    #[archive_method(transform_bounds(T))]
+    pub fn convert_first<U>(&self) -> Option<U>
+    where
+        T: Clone,
+        U: From<T>,
+    {
+        self.elements.first().cloned().map(U::from)
+    }
+}
+
+pub fn call_archived<T, U>(foo: &ArchivedFoo<T>) -> Option<U>
+where
+    T: Archive,
+    T::Archived: Clone,
+    U: From<T::Archived>,
+{
+    foo.convert_first()
+}
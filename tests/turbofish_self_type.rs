@@ -0,0 +1,33 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T> {
+    elements: Vec<T>,
+}
+
+// The generated block is `impl<T: Archive> ArchivedFoo::<T>`.
+#[rustfmt::skip]
+#[archive_impl(add_bounds(T: Archive))]
+impl<T> Foo::<T> {
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
+
+#[test]
+fn turbofish_is_kept() {
+    let foo = Foo {
+        elements: vec![1u32, 2],
+    };
+    let bytes = common::archive(&foo);
+    let archived: &ArchivedFoo<u32> = bytes.root();
+    assert_eq!(foo.len(), archived.len());
+    assert!(!archived.is_empty());
+}
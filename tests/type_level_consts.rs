@@ -0,0 +1,43 @@
+use rkyv::Archive;
+use rkyv_impl::*;
+
+// A stand-in for `typenum`, which encodes constants as types.
+pub mod consts {
+    pub struct U4;
+
+    // Shares its name with the transformed param below.
+    #[allow(non_camel_case_types)]
+    pub struct T;
+}
+
+pub trait ArrayLength<E> {
+    type Output;
+}
+
+pub trait Tagged<Tag> {}
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    value: T,
+}
+
+// Only the bounded `T` becomes `T::Archived`; `consts::U4` and `consts::T` are
+// left alone.
+#[archive_impl(transform_bounds(T))]
+impl<T> Foo<T>
+where
+    T: ArrayLength<u8, Output = consts::U4> + Tagged<consts::T>,
+{
+    #[archive_method(transform_return(T))]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+pub fn call_archived<T>(foo: &ArchivedFoo<T>) -> &T::Archived
+where
+    T: Archive,
+    T::Archived: ArrayLength<u8, Output = consts::U4> + Tagged<consts::T>,
+{
+    foo.value()
+}
@@ -0,0 +1,24 @@
+use rkyv::Archive;
+use rkyv_impl::archive_impl;
+
+#[derive(Archive)]
+pub struct Foo<T> {
+    field: T,
+}
+
+/// # Safety
+///
+/// Only for testing.
+pub unsafe trait Marker {}
+
+// Both impls must keep `unsafe`, or they wouldn't compile.
+#[archive_impl(add_bounds(T: Archive))]
+unsafe impl<T> Marker for Foo<T> {}
+
+fn assert_marker<M: Marker>() {}
+
+#[test]
+fn both_impls_are_unsafe() {
+    assert_marker::<Foo<u32>>();
+    assert_marker::<ArchivedFoo<u32>>();
+}
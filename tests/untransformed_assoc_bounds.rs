@@ -0,0 +1,50 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+pub trait Container {
+    type Item;
+}
+
+impl<V> Container for Vec<V> {
+    type Item = V;
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Foo<T, U> {
+    value: T,
+    items: U,
+}
+
+// Only `T` is transformed, so `U::Item: Clone` is left as written.
+#[archive_impl(transform_bounds(T), add_bounds(U: Archive))]
+impl<T, U> Foo<T, U>
+where
+    T: PartialEq,
+    U: Container,
+    U::Item: Clone,
+{
+    pub fn value_eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+
+    pub fn clone_item(&self, item: &U::Item) -> U::Item {
+        item.clone()
+    }
+}
+
+#[test]
+fn other_param_assoc_type_is_untouched() {
+    let foo = Foo {
+        value: 1u32,
+        items: vec!["a".to_string()],
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+
+    assert!(archived.value_eq(archived));
+    // Takes a `String`, not an `ArchivedString`.
+    let item: String = archived.clone_item(&"b".to_string());
+    assert_eq!(item, foo.clone_item(&"b".to_string()));
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+
+#[derive(Archive, rkyv::Serialize)]
+#[archive(bound(archive = "T: Archive"))]
+pub struct Foo<T> {
+    value: T,
+}
+
+// Bounds are added after the trailing comma without doubling it.
+#[archive_impl(transform_bounds(T), add_bounds(T: Clone), preserve_bounds(T: Send))]
+impl<T: Sync> Foo<T>
+where
+    T: PartialEq + Send,
+{
+    #[archive_method(add_bounds(T: Sync))]
+    pub fn value_eq(&self, other: &Self) -> bool
+    where
+        T: Clone,
+    {
+        self.value == other.value
+    }
+}
+
+#[derive(Archive, rkyv::Serialize)]
+pub struct Bar {
+    value: u32,
+}
+
+// An empty `where` clause gets the added bounds.
+#[archive_impl(add_bounds(u32: Copy))]
+impl Bar {
+    #[archive_method(add_bounds(u32: Clone))]
+    pub fn value(&self) -> u32
+where {
+        self.value
+    }
+}
+
+#[test]
+fn trailing_comma_where_clause() {
+    let foo = Foo { value: 1u32 };
+    assert!(foo.value_eq(&foo));
+
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+    assert!(archived.value_eq(archived));
+}
+
+#[test]
+fn empty_where_clause() {
+    let bar = Bar { value: 3 };
+    assert_eq!(bar.value(), 3);
+
+    let bytes = common::archive(&bar);
+    let archived = bytes.root();
+    assert_eq!(archived.value(), 3);
+}
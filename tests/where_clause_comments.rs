@@ -0,0 +1,62 @@
+mod common;
+
+use rkyv::Archive;
+use rkyv_impl::*;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+#[derive(Archive, rkyv::Serialize)]
+#[archive(bound(archive = "T: Archive, U: Archive"))]
+pub struct Foo<T, U> {
+    first: T,
+    second: U,
+}
+
+// Comments don't make it into the token stream, and every predicate is needed
+// by one of the method bodies, so a dropped `T` bound fails to compile.
+#[archive_impl(transform_bounds(T), add_bounds(U: Archive, U::Archived: Copy))]
+impl<T, U> Foo<T, U>
+where
+    // Equality of the first field.
+    T: PartialEq + Eq,
+    /* Hashing and ordering. */
+    T: Hash
+        // Spread over several lines.
+        + Ord,
+    // Printing.
+    T: Debug, // Trailing comment.
+    U: Copy,
+{
+    pub fn first_eq(&self, other: &Self) -> bool {
+        self.first == other.first
+    }
+
+    pub fn first_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.first.cmp(&other.first)
+    }
+
+    pub fn first_hash(&self) -> u64 {
+        use std::hash::{BuildHasher, BuildHasherDefault};
+        BuildHasherDefault::<std::collections::hash_map::DefaultHasher>::default()
+            .hash_one(&self.first)
+    }
+
+    pub fn first_debug(&self) -> String {
+        format!("{:?}", self.first)
+    }
+}
+
+#[test]
+fn multi_line_where_clause() {
+    let foo = Foo {
+        first: 1u32,
+        second: 2u8,
+    };
+    let bytes = common::archive(&foo);
+    let archived = bytes.root();
+
+    assert!(archived.first_eq(archived));
+    assert_eq!(archived.first_cmp(archived), std::cmp::Ordering::Equal);
+    assert_eq!(archived.first_hash(), foo.first_hash());
+    assert_eq!(archived.first_debug(), foo.first_debug());
+}